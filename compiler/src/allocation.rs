@@ -323,7 +323,7 @@ pub fn allocate(
                     vec![operand1_location, operand2_location],
                 ));
             }
-            "push" | "print" => {
+            "push" | "print" | "printc" => {
                 let (operand1_location, new_pointer) = get_operand_location(
                     &instruction.operands[0],
                     &mut variable_map,
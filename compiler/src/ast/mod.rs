@@ -8,7 +8,7 @@ mod function;
 pub mod node;
 mod parser;
 
-use function::Function;
+pub use function::Function;
 pub use node::{Node, NodeKind};
 pub use parser::Parser;
 
@@ -79,9 +79,9 @@ impl AST {
                     Self::print_block(vec![lparam], f, level + 1)?;
                     Self::print_block(vec![rparam], f, level + 1)?;
                 }
-                NodeKind::Print { value } => {
+                NodeKind::Print { values } => {
                     writeln!(f, "{}Print", prefix)?;
-                    Self::print_block(vec![value], f, level + 1)?;
+                    Self::print_block(values, f, level + 1)?;
                 }
                 NodeKind::Comparison {
                     lparam,
@@ -119,6 +119,10 @@ impl AST {
                     writeln!(f, "{}Return", prefix)?;
                     Self::print_block(vec![value], f, level + 1)?;
                 }
+                NodeKind::Assert { condition } => {
+                    writeln!(f, "{}Assert", prefix)?;
+                    Self::print_block(vec![condition], f, level + 1)?;
+                }
             }
         }
 
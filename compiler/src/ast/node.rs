@@ -107,7 +107,7 @@ pub enum NodeKind {
         operation: OperationType,
     },
     Print {
-        value: Box<Node>,
+        values: CodeBlock, // Values printed on the same line
     },
     Comparison {
         lparam: Box<Node>,
@@ -132,6 +132,9 @@ pub enum NodeKind {
     Return {
         value: Box<Node>,
     },
+    Assert {
+        condition: Box<Node>, // Comparison or value, evaluated at compile time
+    },
 }
 
 impl NodeKind {
@@ -198,7 +201,16 @@ impl fmt::Display for NodeKind {
                     .join("\n")
             ),
             NodeKind::Return { value } => write!(f, "ret {}", value),
-            NodeKind::Print { value } => write!(f, "Print {}", value),
+            NodeKind::Assert { condition } => write!(f, "assert {}", condition),
+            NodeKind::Print { values } => write!(
+                f,
+                "Print {}",
+                values
+                    .iter()
+                    .map(|n| format!("{}", n))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
             NodeKind::Operation {
                 lparam,
                 rparam,
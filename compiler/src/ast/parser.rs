@@ -8,6 +8,7 @@ use super::node::{CodeBlock, ComparisonType, Node, NodeKind, OperationType};
 use super::AST;
 
 use std::collections::HashMap;
+use std::num::IntErrorKind;
 
 /// A recursive descent parser using token slice with index for efficient parsing.
 ///
@@ -80,6 +81,17 @@ impl<'a> Parser<'a> {
         matches!(self.peek(), Some(Token { kind: TokenKind::Keyword(kw), .. }) if *kw == keyword)
     }
 
+    /// Returns the name of the current token if it is a constant
+    fn peek_constant(&self) -> Option<String> {
+        match self.peek() {
+            Some(Token {
+                kind: TokenKind::Ident(name),
+                ..
+            }) if constant_value(name).is_some() => Some(name.to_string()),
+            _ => None,
+        }
+    }
+
     /// Check if current token is a specific symbol
     fn check_symbol(&self, symbol: SymbolKind) -> bool {
         matches!(self.peek(), Some(Token { kind: TokenKind::Symbol(s), .. }) if *s == symbol)
@@ -196,6 +208,13 @@ impl<'a> Parser<'a> {
         let mut params = Vec::new();
 
         while !self.check_symbol(SymbolKind::RightParen) && !self.is_at_end() {
+            if let Some(name) = self.peek_constant() {
+                return Err(TokenError::new(
+                    TokenErrorType::UnexpectedToken,
+                    format!("cannot use constant `{}` as a parameter", name),
+                    self.current_location(),
+                ));
+            }
             if let Some(Token {
                 kind: TokenKind::Ident(name),
                 ..
@@ -269,6 +288,11 @@ impl<'a> Parser<'a> {
                 self.advance();
                 self.parse_print()
             }
+            Some(TokenKind::Keyword(KeywordKind::Assert)) => {
+                let location = self.current_location();
+                self.advance();
+                self.parse_assert(location)
+            }
             Some(kind) => Err(TokenError::new(
                 TokenErrorType::UnexpectedToken,
                 format!("Unexpected token in statement: {:?}", kind),
@@ -289,6 +313,13 @@ impl<'a> Parser<'a> {
 
     /// Parse an assignment: set <ident> = <expr>
     fn parse_assignment(&mut self) -> Result<Node, TokenError> {
+        if let Some(name) = self.peek_constant() {
+            return Err(TokenError::new(
+                TokenErrorType::UnexpectedToken,
+                format!("cannot assign to constant `{}`", name),
+                self.current_location(),
+            ));
+        }
         let lparam = self.parse_primary()?;
 
         // Expect '='
@@ -395,17 +426,41 @@ impl<'a> Parser<'a> {
         }
     }
 
-    /// Parse a print statement: print <expr>
+    /// Parse a print statement: print <expr>[, <expr>...]
     fn parse_print(&mut self) -> Result<Node, TokenError> {
-        let value = self.parse_primary()?;
-        Ok(Node::new(NodeKind::Print {
-            value: Box::new(value),
-        }))
+        let mut values = vec![Box::new(self.parse_primary()?)];
+        while self.match_symbol(SymbolKind::Separator) {
+            values.push(Box::new(self.parse_primary()?));
+        }
+
+        Ok(Node::new(NodeKind::Print { values }))
+    }
+
+    /// Parse a compile-time assertion: assert <condition>
+    fn parse_assert(&mut self, location: Option<TokenLocation>) -> Result<Node, TokenError> {
+        // Both sides are folded at compile time, so they may be full expressions
+        let condition = self.parse_comparison_of(Self::parse_expression)?;
+        let kind = NodeKind::Assert {
+            condition: Box::new(condition),
+        };
+
+        Ok(match location {
+            Some(location) => Node::with_span(kind, location),
+            None => Node::new(kind),
+        })
     }
 
     /// Parse a comparison expression: <expr> <cmp_op> <expr>
     fn parse_comparison(&mut self) -> Result<Node, TokenError> {
-        let lparam = self.parse_primary()?;
+        self.parse_comparison_of(Self::parse_primary)
+    }
+
+    /// Parse a comparison whose sides are parsed with `parse_operand`
+    fn parse_comparison_of(
+        &mut self,
+        parse_operand: fn(&mut Self) -> Result<Node, TokenError>,
+    ) -> Result<Node, TokenError> {
+        let lparam = parse_operand(self)?;
 
         if let Some(Token {
             kind: TokenKind::Comp(cmp),
@@ -423,7 +478,7 @@ impl<'a> Parser<'a> {
             let loc = location.clone();
             self.advance();
 
-            let rparam = self.parse_primary()?;
+            let rparam = parse_operand(self)?;
 
             Ok(Node::with_span(
                 NodeKind::Comparison {
@@ -434,7 +489,7 @@ impl<'a> Parser<'a> {
                 loc,
             ))
         } else {
-            // No comparison operator, just return the primary
+            // No comparison operator, just return the operand
             Ok(lparam)
         }
     }
@@ -505,24 +560,50 @@ impl<'a> Parser<'a> {
         Ok(left)
     }
 
+    /// Parse an integer literal (decimal or hexadecimal), negated if it follows a unary minus
+    fn parse_literal(&mut self, negative: bool) -> Result<Node, TokenError> {
+        let Some(Token {
+            kind: TokenKind::Literal(value),
+            location,
+        }) = self.peek()
+        else {
+            return Err(TokenError::new(
+                TokenErrorType::NotALiteral,
+                "Expected integer literal",
+                self.current_location(),
+            ));
+        };
+
+        let location = location.clone();
+        let sign = if negative { "-" } else { "" };
+        let digits = value.replace("_", "");
+        let parsed = match digits.strip_prefix("0x") {
+            Some(hex) => i32::from_str_radix(&format!("{}{}", sign, hex), 16),
+            None => format!("{}{}", sign, digits).parse::<i32>(),
+        };
+        let value: i32 = parsed.map_err(|e| match e.kind() {
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => TokenError::new(
+                TokenErrorType::OutOfRange,
+                format!("integer literal out of range for i32: {}{}", sign, value),
+                self.current_location(),
+            ),
+            _ => TokenError::new(
+                TokenErrorType::ParseError,
+                format!("Invalid integer literal: {}{}", sign, value),
+                self.current_location(),
+            ),
+        })?;
+        self.advance();
+        Ok(Node::with_span(NodeKind::Litteral { value }, location))
+    }
+
     /// Parse a primary expression (identifier, literal, or parenthesized expression)
     fn parse_primary(&mut self) -> Result<Node, TokenError> {
         match self.peek() {
             Some(Token {
-                kind: TokenKind::Literal(value),
-                location,
-            }) => {
-                let location = location.clone();
-                let value: i32 = value.replace("_", "").parse().map_err(|_| {
-                    TokenError::new(
-                        TokenErrorType::ParseError,
-                        format!("Invalid integer literal: {}", value),
-                        self.current_location(),
-                    )
-                })?;
-                self.advance();
-                Ok(Node::with_span(NodeKind::Litteral { value }, location))
-            }
+                kind: TokenKind::Literal(_),
+                ..
+            }) => self.parse_literal(false),
             Some(Token {
                 kind: TokenKind::Ident(name),
                 location,
@@ -531,6 +612,11 @@ impl<'a> Parser<'a> {
                 let location = location.clone();
                 self.advance();
 
+                // Constants are replaced by their value
+                if let Some(value) = constant_value(&name) {
+                    return Ok(Node::with_span(NodeKind::Litteral { value }, location));
+                }
+
                 // Create base node: MemoryValue for $identifier, Identifier otherwise
                 let base_node = if name.starts_with('$') {
                     Node::with_span(
@@ -573,12 +659,27 @@ impl<'a> Parser<'a> {
             }) => {
                 let location = location.clone();
                 self.advance(); // consume '-'
+
+                // The sign is part of the literal, so that i32::MIN can be written
+                if let Some(Token {
+                    kind: TokenKind::Literal(_),
+                    ..
+                }) = self.peek()
+                {
+                    let literal = self.parse_literal(true)?;
+                    return Ok(Node::with_span(literal.kind, location));
+                }
+
                 let inner = self.parse_primary()?;
                 match inner.kind {
-                    NodeKind::Litteral { value } => Ok(Node::with_span(
-                        NodeKind::Litteral { value: -value },
-                        location,
-                    )),
+                    NodeKind::Litteral { value } => match value.checked_neg() {
+                        Some(value) => Ok(Node::with_span(NodeKind::Litteral { value }, location)),
+                        None => Err(TokenError::new(
+                            TokenErrorType::OutOfRange,
+                            format!("integer literal out of range for i32: -({})", value),
+                            Some(location),
+                        )),
+                    },
                     _ => Err(TokenError::new(
                         TokenErrorType::UnexpectedToken,
                         "Unary minus only supported for literals",
@@ -586,6 +687,15 @@ impl<'a> Parser<'a> {
                     )),
                 }
             }
+            // Statements can not be used as values (e.g. `set x = if ...`)
+            Some(Token {
+                kind: TokenKind::Keyword(keyword),
+                ..
+            }) => Err(TokenError::new(
+                TokenErrorType::UnexpectedToken,
+                format!("keyword `{}` cannot be used as a value here", keyword),
+                self.current_location(),
+            )),
             Some(token) => Err(TokenError::new(
                 TokenErrorType::UnexpectedToken,
                 format!("Expected expression, found {:?}", token.kind),
@@ -623,3 +733,11 @@ impl<'a> Parser<'a> {
         }
     }
 }
+
+/// Returns the value of the constant with the given name, if there is one
+fn constant_value(name: &str) -> Option<i32> {
+    machine::prelude::get_constants()
+        .into_iter()
+        .find(|(constant, _)| constant == name)
+        .map(|(_, value)| value)
+}
@@ -560,8 +560,9 @@ fn test_parse_print_literal() {
     let content = &ast.functions["main"].content;
 
     match &content[0].kind {
-        NodeKind::Print { value } => {
-            match &value.kind {
+        NodeKind::Print { values } => {
+            assert_eq!(values.len(), 1);
+            match &values[0].kind {
                 NodeKind::Litteral { value } => assert_eq!(*value, 42),
                 _ => panic!("Expected literal"),
             }
@@ -577,8 +578,9 @@ fn test_parse_print_identifier() {
     let content = &ast.functions["main"].content;
 
     match &content[0].kind {
-        NodeKind::Print { value } => {
-            match &value.kind {
+        NodeKind::Print { values } => {
+            assert_eq!(values.len(), 1);
+            match &values[0].kind {
                 NodeKind::Identifier { name } => assert_eq!(name, "x"),
                 _ => panic!("Expected identifier"),
             }
@@ -587,6 +589,88 @@ fn test_parse_print_identifier() {
     }
 }
 
+#[test]
+fn test_parse_print_multiple_values() {
+    let code = "fn main() { print x, 42, $Rotation; }";
+    let ast = parse_program(code).unwrap();
+    let content = &ast.functions["main"].content;
+
+    match &content[0].kind {
+        NodeKind::Print { values } => {
+            assert_eq!(values.len(), 3);
+            assert!(matches!(&values[0].kind, NodeKind::Identifier { name } if name == "x"));
+            assert!(matches!(values[1].kind, NodeKind::Litteral { value: 42 }));
+            assert!(matches!(&values[2].kind, NodeKind::MemoryValue { name } if name == "Rotation"));
+        }
+        _ => panic!("Expected print statement"),
+    }
+}
+
+#[test]
+fn test_parse_constant_is_replaced_by_its_value() {
+    let code = "fn main() { set x = RAY_COUNT; }";
+    let ast = parse_program(code).unwrap();
+    let content = &ast.functions["main"].content;
+
+    match &content[0].kind {
+        NodeKind::Assignment { rparam, .. } => {
+            assert!(matches!(
+                rparam.kind,
+                NodeKind::Litteral { value } if value == machine::prelude::RAY_COUNT
+            ));
+        }
+        _ => panic!("Expected assignment"),
+    }
+}
+
+#[test]
+fn test_error_assign_to_constant() {
+    for code in [
+        "fn main() {\n    set RAY_COUNT = 3;\n}",
+        "fn main() {\n    set RAY_COUNT[0] = 3;\n}",
+    ] {
+        let error = format!("{}", parse_program(code).unwrap_err());
+        assert!(
+            error.contains("cannot assign to constant `RAY_COUNT` at line 2, column 9"),
+            "{}",
+            error
+        );
+    }
+}
+
+#[test]
+fn test_error_constant_as_parameter() {
+    let code = "fn scan(count, RAY_COUNT) {\n    return count;\n}";
+    let error = format!("{}", parse_program(code).unwrap_err());
+
+    assert!(
+        error.contains("cannot use constant `RAY_COUNT` as a parameter at line 1, column 16"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn test_parse_assert() {
+    let code = "fn main() {\n    assert RAY_COUNT >= 1;\n}";
+    let ast = parse_program(code).unwrap();
+    let statement = &ast.functions["main"].content[0];
+
+    match &statement.kind {
+        NodeKind::Assert { condition } => {
+            assert!(matches!(
+                condition.kind,
+                NodeKind::Comparison {
+                    comparison: ComparisonType::GE,
+                    ..
+                }
+            ));
+        }
+        _ => panic!("Expected assertion"),
+    }
+    assert_eq!(statement.span.as_ref().map(|span| span.line), Some(2));
+}
+
 // ========================================
 // Memory Access Tests
 // ========================================
@@ -784,6 +868,100 @@ fn test_error_missing_array_rbracket() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_error_decimal_literal_out_of_range() {
+    let code = "fn main() {\n    set x = 3000000000;\n}";
+    let error = format!("{}", parse_program(code).unwrap_err());
+
+    assert!(error.contains("OutOfRange"), "{}", error);
+    assert!(
+        error.contains("integer literal out of range for i32: 3000000000"),
+        "{}",
+        error
+    );
+    assert!(error.contains("line 2, column 13"), "{}", error);
+}
+
+#[test]
+fn test_error_hex_literal_out_of_range() {
+    let code = "fn main() { set x = 0x1_0000_0000; }";
+    let error = format!("{}", parse_program(code).unwrap_err());
+
+    assert!(
+        error.contains("integer literal out of range for i32: 0x1_0000_0000"),
+        "{}",
+        error
+    );
+    assert!(error.contains("line 1, column 21"), "{}", error);
+}
+
+#[test]
+fn test_parse_negative_literal_bounds() {
+    for code in [
+        "fn main() { set x = -2147483648; }",
+        "fn main() { set x = -0x8000_0000; }",
+    ] {
+        let ast = parse_program(code).unwrap();
+
+        match &ast.functions["main"].content[0].kind {
+            NodeKind::Assignment { rparam, .. } => {
+                assert!(matches!(rparam.kind, NodeKind::Litteral { value: i32::MIN }));
+            }
+            _ => panic!("Expected assignment"),
+        }
+    }
+
+    let code = "fn main() { set x = -2147483649; }";
+    let error = format!("{}", parse_program(code).unwrap_err());
+    assert!(
+        error.contains("integer literal out of range for i32: -2147483649"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn test_parse_hex_literal() {
+    let code = "fn main() { set x = 0x7fff_ffff; }";
+    let ast = parse_program(code).unwrap();
+
+    match &ast.functions["main"].content[0].kind {
+        NodeKind::Assignment { rparam, .. } => {
+            assert!(matches!(rparam.kind, NodeKind::Litteral { value: i32::MAX }));
+        }
+        _ => panic!("Expected assignment"),
+    }
+}
+
+#[test]
+fn test_error_keyword_used_as_value() {
+    for keyword in ["fn", "while", "set", "if", "else", "return", "loop", "call", "print", "assert"] {
+        let code = format!("fn main() {{\n    set x = {};\n}}", keyword);
+        let error = format!("{}", parse_program(&code).unwrap_err());
+
+        assert!(
+            error.contains(&format!(
+                "keyword `{}` cannot be used as a value here at line 2, column 13",
+                keyword
+            )),
+            "{}",
+            error
+        );
+    }
+}
+
+#[test]
+fn test_error_keyword_used_as_operand() {
+    let code = "fn main() { set x = 1 + while; }";
+    let error = format!("{}", parse_program(code).unwrap_err());
+
+    assert!(
+        error.contains("keyword `while` cannot be used as a value here at line 1, column 25"),
+        "{}",
+        error
+    );
+}
+
 // ========================================
 // Error Metadata Tests
 // ========================================
@@ -1,12 +1,10 @@
 use clap::Parser;
-use std::collections::HashMap;
 use std::fs;
 
 use colog;
 use log::{error, info, warn};
 
 use afgcompiler::prelude::*;
-use afgcompiler::lexer::parse_source;
 
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
@@ -29,71 +27,6 @@ fn main() -> Result<(), String> {
     info!("Reading source code from {}", &args.input);
     let text = fs::read_to_string(&args.input).map_err(|e| e.to_string())?;
 
-    info!("Extracting tokens");
-    let lex_result = parse_source(&text);
-
-    // Report any lexer errors
-    if !lex_result.errors.is_empty() {
-        for err in &lex_result.errors {
-            error!("Lexer error: {} - (Line: {}, column: {})", err.message, err.location.line, err.location.column);
-        }
-        return Err("Lexer encountered errors".to_string());
-    }
-
-    if args.save_intermediate {
-        let token_output = args.input.clone() + ".tokens";
-        info!("Saving tokens to {}", token_output);
-        fs::write(
-            &token_output,
-            format!(
-                "{}",
-                lex_result.tokens
-                    .iter()
-                    .map(|t| format!("{:?}", t))
-                    .collect::<Vec<String>>()
-                    .join("\n")
-            ),
-        )
-        .map_err(|e| e.to_string())?;
-    }
-
-    info!("Parsing AST from tokens");
-    let program = AST::parse_tokens(lex_result.tokens).map_err(|e| format!("{}", e))?;
-    if args.save_intermediate {
-        let ast_output = args.input.clone() + ".ast";
-        info!("Saving AST to {}", ast_output);
-        fs::write(&ast_output, format!("{}", program)).map_err(|e| e.to_string())?;
-    }
-
-    info!("Analyzing AST");
-    analyze(&program).map_err(|e| format!("{}", e))?;
-
-    info!("Generating pseudo-asm");
-    let pasm = PASMProgram::parse(program)?;
-    if args.save_intermediate {
-        let pasm_output = args.input.clone() + ".pasm";
-        info!("Saving pseudo-asm to {}", pasm_output);
-        fs::write(&pasm_output, format!("{}", pasm)).map_err(|e| e.to_string())?;
-    }
-
-    info!("Allocating static memory");
-    let allocated_program = PASMAllocatedProgram {
-        functions: pasm
-            .functions
-            .iter()
-            .map(
-                |(function_name, function)| -> Result<(String, Vec<PASMInstruction>), String> {
-                    Ok((function_name.clone(), allocate(function)?))
-                },
-            )
-            .collect::<Result<HashMap<String, Vec<PASMInstruction>>, String>>()?,
-    };
-    if args.save_intermediate {
-        let pasm_output = args.input.clone() + ".pasm_allocated";
-        info!("Saving allocated pseudo-asm to {}", pasm_output);
-        fs::write(&pasm_output, format!("{}", allocated_program)).map_err(|e| e.to_string())?;
-    }
-
     if args.optimize {
         warn!("Code optimization is not complete yet and might lead to buggy programs");
         error!("Code optimization is not implemented yet");
@@ -101,23 +34,46 @@ fn main() -> Result<(), String> {
         // let analyzed = PASMProgramWithInterferenceGraph::analyse(&pasm)?;
     }
 
-    // Final step; resolve labels and write to output file
-    let mut final_code = allocated_program
-        .functions
-        .get("main")
-        .ok_or("No main function")?
-        .clone();
+    let save = |extension: &str, description: &str, content: String| -> Result<(), String> {
+        if args.save_intermediate {
+            let output = format!("{}.{}", args.input, extension);
+            info!("Saving {} to {}", description, output);
+            fs::write(&output, content).map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    };
 
-    for (function_name, function) in allocated_program.functions.into_iter() {
-        if function_name == "main" {
-            continue;
+    info!("Extracting tokens");
+    let final_code = compile_with(&text, |stage| match stage {
+        Stage::Tokens(tokens) => {
+            save(
+                "tokens",
+                "tokens",
+                tokens
+                    .iter()
+                    .map(|t| format!("{:?}", t))
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            )?;
+            info!("Parsing AST from tokens");
+            Ok(())
         }
-        final_code.push(PASMInstruction::new_comment(format!(
-            "Function {}",
-            function_name
-        )));
-        final_code.extend(function);
-    }
+        Stage::Ast(program) => {
+            save("ast", "AST", format!("{}", program))?;
+            info!("Analyzing AST");
+            Ok(())
+        }
+        Stage::Pasm(pasm) => {
+            save("pasm", "pseudo-asm", format!("{}", pasm))?;
+            info!("Allocating static memory");
+            Ok(())
+        }
+        Stage::Allocated(allocated_program) => save(
+            "pasm_allocated",
+            "allocated pseudo-asm",
+            format!("{}", allocated_program),
+        ),
+    })?;
 
     info!("Resolving labels");
     resolve_labels(final_code)
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use super::allocation::allocate;
+use super::ast::AST;
+use super::labels::resolve_labels;
+use super::lexer::parse_source;
+use super::lexer::token::Token;
+use super::pasm::{PASMAllocatedProgram, PASMInstruction, PASMProgram};
+use super::semantic::analyze;
+
+/// Intermediate result of a compilation stage, given to the inspector of `compile_with`
+pub enum Stage<'a> {
+    Tokens(&'a [Token<'a>]),
+    Ast(&'a AST),
+    Pasm(&'a PASMProgram),
+    Allocated(&'a PASMAllocatedProgram),
+}
+
+/// Goes through all the compilation stages but the label resolution. `inspect` is called with
+/// the result of each stage, an error returned by it stops the compilation.
+///
+/// The `main` function comes first in the returned program, so that the program starts with it,
+/// the other functions follow with a comment naming them. Labels are left unresolved, see
+/// `resolve_labels` and `resolve_labels_with_debug_info`.
+pub fn compile_with<F>(source: &str, mut inspect: F) -> Result<Vec<PASMInstruction>, String>
+where
+    F: FnMut(Stage) -> Result<(), String>,
+{
+    let lex_result = parse_source(source);
+    if !lex_result.errors.is_empty() {
+        return Err(lex_result
+            .errors
+            .iter()
+            .map(|error| {
+                format!(
+                    "Lexer error: {} - (Line: {}, column: {})",
+                    error.message, error.location.line, error.location.column
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n"));
+    }
+    inspect(Stage::Tokens(&lex_result.tokens))?;
+
+    let program = AST::parse_tokens(lex_result.tokens).map_err(|e| format!("{}", e))?;
+    inspect(Stage::Ast(&program))?;
+    analyze(&program).map_err(|e| format!("{}", e))?;
+
+    let pasm = PASMProgram::parse(program)?;
+    inspect(Stage::Pasm(&pasm))?;
+
+    let mut allocated = PASMAllocatedProgram {
+        functions: pasm
+            .functions
+            .iter()
+            .map(|(name, function)| Ok((name.clone(), allocate(function)?)))
+            .collect::<Result<HashMap<String, Vec<PASMInstruction>>, String>>()?,
+    };
+    inspect(Stage::Allocated(&allocated))?;
+
+    let mut final_code = allocated
+        .functions
+        .remove("main")
+        .ok_or("No main function")?;
+    for (function_name, function) in allocated.functions.into_iter() {
+        final_code.push(PASMInstruction::new_comment(format!(
+            "Function {}",
+            function_name
+        )));
+        final_code.extend(function);
+    }
+
+    Ok(final_code)
+}
+
+/// Compiles AFG source code down to AsmFG, going through all the compilation stages at once.
+/// The `main` function comes first in the output, so that the program starts with it.
+///
+/// # Example
+/// ```rust
+/// use afgcompiler::prelude::compile;
+///
+/// let asmfg = compile("fn main() { set x = 42; print x; }").unwrap();
+/// ```
+pub fn compile(source: &str) -> Result<String, String> {
+    let code = compile_with(source, |_| Ok(()))?;
+
+    Ok(resolve_labels(code)?
+        .iter()
+        .map(|i| format!("{}", i))
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
@@ -9,6 +9,7 @@ pub enum TokenErrorType {
     UnexpectedToken,
     UnexpectedEndOfStream,
     ParseError,
+    OutOfRange,
     EmptyToken,
     InvalidArithmeticOperator,
     InvalidComparisonOperator,
@@ -1,20 +1,35 @@
 use std::collections::HashMap;
 
+use machine::prelude::{is_function_label, DebugInfo};
+
 use super::pasm::{OperandType, PASMInstruction};
 
 pub fn resolve_labels(function: Vec<PASMInstruction>) -> Result<Vec<PASMInstruction>, String> {
+    resolve_labels_with_debug_info(function).map(|(resolved, _)| resolved)
+}
+
+/// Resolves the labels of the program like `resolve_labels` and additionally returns the debug
+/// information of the program (where each label points to and the source line of each instruction)
+pub fn resolve_labels_with_debug_info(
+    function: Vec<PASMInstruction>,
+) -> Result<(Vec<PASMInstruction>, DebugInfo), String> {
     let mut current_line: usize = 0;
     let mut label_map: HashMap<String, usize> = HashMap::new();
     let mut resolved = Vec::new();
+    let mut debug_info = DebugInfo::new();
 
     let ignore_comments = true;
 
     for inst in function {
         if inst.is_label {
+            debug_info.add_label(current_line, &inst.opcode);
             label_map.insert(inst.opcode.clone(), current_line);
         } else if inst.is_comment && ignore_comments {
            continue;
         } else {
+            if let Some(span) = &inst.span {
+                debug_info.add_source_line(current_line, span.line);
+            }
             resolved.push(inst);
             current_line += 1;
         }
@@ -22,6 +37,13 @@ pub fn resolve_labels(function: Vec<PASMInstruction>) -> Result<Vec<PASMInstruct
 
     for (current_line, inst) in resolved.iter_mut().enumerate() {
         if let Some(jump_to) = inst.jump_to() {
+            // Functions set up their frame in their first instructions, a call must land on one
+            if inst.opcode == "call" && !is_function_label(&jump_to) {
+                return Err(format!(
+                    "Call to label {} which is not a function entry",
+                    jump_to
+                ));
+            }
             if let Some(line) = label_map.get(&jump_to) {
                 inst.operands = vec![OperandType::Literal {
                     value: *line as i32 - current_line as i32,
@@ -32,5 +54,5 @@ pub fn resolve_labels(function: Vec<PASMInstruction>) -> Result<Vec<PASMInstruct
         }
     }
 
-    Ok(resolved)
+    Ok((resolved, debug_info))
 }
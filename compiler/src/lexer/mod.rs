@@ -53,6 +53,7 @@ fn keywords_parser<'a>() -> impl Parser<Span<'a>, Output = Token<'a>, Error = Er
         terminated(
             alt((
                 tag("return"),
+                tag("assert"),
                 tag("print"),
                 tag("while"),
                 tag("else"),
@@ -77,6 +78,7 @@ fn keywords_parser<'a>() -> impl Parser<Span<'a>, Output = Token<'a>, Error = Er
                 "loop" => token::KeywordKind::Loop,
                 "call" => token::KeywordKind::Call,
                 "print" => token::KeywordKind::Print,
+                "assert" => token::KeywordKind::Assert,
                 _ => unreachable!(),
             }),
             location: TokenLocation::new(&lexeme),
@@ -139,9 +141,19 @@ fn whitespace_parser<'a>() -> impl Parser<Span<'a>, Output = (), Error = Error<S
     value((), many1(one_of(" \t\r\n")))
 }
 
+/// Parses decimal (`1_000`) and hexadecimal (`0xff`) integer literals
 fn literals_parser<'a>() -> impl Parser<Span<'a>, Output = Token<'a>, Error = Error<Span<'a>>> {
     map(
-        recognize(many1(terminated(one_of("0123456789"), many0(char('_'))))),
+        alt((
+            recognize(pair(
+                tag("0x"),
+                many1(terminated(
+                    one_of("0123456789abcdefABCDEF"),
+                    many0(char('_')),
+                )),
+            )),
+            recognize(many1(terminated(one_of("0123456789"), many0(char('_'))))),
+        )),
         |lexeme: Span| Token {
             kind: TokenKind::Literal(lexeme.fragment()),
             location: TokenLocation::new(&lexeme),
@@ -391,6 +391,15 @@ mod literals_parser_tests {
         let (_, token) = result.unwrap();
         assert_eq!(token.kind, TokenKind::Literal("0"));
     }
+
+    #[test]
+    fn test_hex_number() {
+        let result = literals_parser().parse(Span::new("0xff_FF;"));
+        assert!(result.is_ok());
+        let (remaining, token) = result.unwrap();
+        assert_eq!(token.kind, TokenKind::Literal("0xff_FF"));
+        assert_eq!(*remaining.fragment(), ";");
+    }
 }
 
 mod identifier_parser_tests {
@@ -877,9 +886,9 @@ mod parse_source_tests {
 
         #[test]
         fn test_all_keywords() {
-            let result = parse_source("fn while set if else return loop call print");
+            let result = parse_source("fn while set if else return loop call print assert");
             assert!(result.is_ok());
-            assert_eq!(result.tokens.len(), 9);
+            assert_eq!(result.tokens.len(), 10);
         }
 
         #[test]
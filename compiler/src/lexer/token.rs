@@ -1,3 +1,5 @@
+use std::fmt;
+
 use super::utils::Span;
 
 #[derive(Debug)]
@@ -47,6 +49,25 @@ pub enum KeywordKind {
     Loop,
     Call,
     Print,
+    Assert,
+}
+
+impl fmt::Display for KeywordKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let repr = match self {
+            KeywordKind::Fn => "fn",
+            KeywordKind::While => "while",
+            KeywordKind::Set => "set",
+            KeywordKind::If => "if",
+            KeywordKind::Else => "else",
+            KeywordKind::Return => "return",
+            KeywordKind::Loop => "loop",
+            KeywordKind::Call => "call",
+            KeywordKind::Print => "print",
+            KeywordKind::Assert => "assert",
+        };
+        write!(f, "{}", repr)
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -104,6 +104,7 @@
 /// extensible and maintainable.
 pub mod allocation;
 pub mod ast;
+mod compile;
 pub mod error;
 pub mod labels;
 pub mod lexer;
@@ -114,7 +115,8 @@ pub mod semantic;
 pub mod prelude {
     pub use super::allocation::allocate;
     pub use super::ast::{node::NodeKind, AST};
-    pub use super::labels::resolve_labels;
+    pub use super::compile::{compile, compile_with, Stage};
+    pub use super::labels::{resolve_labels, resolve_labels_with_debug_info};
     pub use super::lexer::parse_source;
     pub use super::liveness::PASMProgramWithInterferenceGraph;
     pub use super::pasm::{PASMAllocatedProgram, PASMInstruction, PASMProgram};
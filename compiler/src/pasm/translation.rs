@@ -581,19 +581,34 @@ fn ret_to_asm(value: &Box<Node>) -> MaybeInstructions {
     Ok(instructions)
 }
 
-/// Produces a print instruction from the AST nodes
-fn print_to_asm(node: &Box<Node>) -> MaybeInstructions {
-    let (operand, mut instructions) = match &node.kind {
-        NodeKind::Identifier { .. } | NodeKind::Litteral { .. } => {
-            (super::assignment::ensure_immediate(node)?, vec![])
-        }
-        NodeKind::MemoryOffset { .. } | NodeKind::MemoryValue { .. } => {
-            super::assignment::ensure_memory(node)?
-        }
-        _ => return Err("Invalid value to print".to_string()),
-    };
+/// Produces the print instructions from the AST nodes.
+/// All the values but the last one are appended to the output line with `printc`,
+/// the last one is printed with `print` which outputs the whole line.
+fn print_to_asm(values: &[Box<Node>]) -> MaybeInstructions {
+    let mut instructions = vec![];
+
+    for (index, node) in values.iter().enumerate() {
+        let (operand, value_instructions) = match &node.kind {
+            NodeKind::Identifier { .. } | NodeKind::Litteral { .. } => {
+                (super::assignment::ensure_immediate(node)?, vec![])
+            }
+            NodeKind::MemoryOffset { .. } | NodeKind::MemoryValue { .. } => {
+                super::assignment::ensure_memory(node)?
+            }
+            _ => return Err("Invalid value to print".to_string()),
+        };
+
+        instructions.extend(value_instructions);
+        instructions.push(PASMInstruction::new(
+            if index + 1 == values.len() {
+                "print".to_string()
+            } else {
+                "printc".to_string()
+            },
+            vec![operand],
+        ));
+    }
 
-    instructions.push(PASMInstruction::new("print".to_string(), vec![operand]));
     Ok(instructions)
 }
 
@@ -608,12 +623,14 @@ pub fn inst_to_pasm(node: &Box<Node>) -> MaybeInstructions {
         NodeKind::IfCondition { condition, content } => if_to_asm(condition, content, None)?,
         NodeKind::Loop { content } => loop_to_asm(content)?,
         NodeKind::WhileLoop { condition, content } => while_to_asm(condition, content)?,
-        NodeKind::Print { value } => print_to_asm(value)?,
+        NodeKind::Print { values } => print_to_asm(values)?,
         NodeKind::FunctionCall {
             function_name,
             parameters,
         } => function_to_asm(function_name, parameters)?,
         NodeKind::Return { value } => ret_to_asm(value)?,
+        // Assertions are checked during the semantic analysis and leave no code behind
+        NodeKind::Assert { .. } => vec![],
         _ => return Err("Not implemented".to_string()),
     };
 
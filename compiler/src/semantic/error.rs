@@ -6,6 +6,7 @@ pub enum SemanticError {
     InvalidOperation(String), // Invalid operation
     UnknownFunction(String), // Call to an undefined function
     InvalidFunctionCall(String), // Function called with incorrect number of parameters
+    AssertionFailed(String), // Compile-time assertion that does not hold
 }
 
 impl fmt::Display for SemanticError {
@@ -15,6 +16,7 @@ impl fmt::Display for SemanticError {
             Self::InvalidOperation(value) => write!(f, "[Semantic] Invalid Operation: {}", value),
             Self::UnknownFunction(value) => write!(f, "[Semantic] Unknown Function: {}", value),
             Self::InvalidFunctionCall(value) => write!(f, "[Semantic] Invalid Function Call: {}", value),
+            Self::AssertionFailed(value) => write!(f, "[Semantic] Assertion Failed: {}", value),
         }
     }
 }
@@ -8,33 +8,62 @@ use crate::ast::node::{CodeBlock, NodeKind};
 mod error;
 mod utils;
 mod validity;
+mod variables;
 
 pub use error::SemanticError;
 pub use utils::*;
+pub use variables::{referenced_variables, variable_kind, VariableKind};
+
+/// Returns a hint pointing at the name the unknown variable is likely a typo of, if any.
+/// Variables in scope are preferred over the parameters of other functions.
+fn suggest_variable(var: &str, scope: &[String], other_parameters: &[(String, String)]) -> String {
+    if let Some(name) = closest_name(var, scope) {
+        return format!(", did you mean `{}`?", name);
+    }
+
+    closest_name(var, other_parameters.iter().map(|(name, _)| name))
+        .and_then(|name| other_parameters.iter().find(|(parameter, _)| parameter == name))
+        .map(|(name, function)| {
+            if name == var {
+                format!(", `{}` is a parameter of function {}, not of this function", name, function)
+            } else {
+                format!(", did you mean `{}` (parameter of function {})?", name, function)
+            }
+        })
+        .unwrap_or_default()
+}
 
 /// Analyzes a block of code for semantic errors
-fn analyze_block(block: &CodeBlock, mut scope: Vec<String>, functions: &HashMap<String, usize>) -> Result<(), SemanticError> {
+fn analyze_block(
+    block: &CodeBlock,
+    mut scope: Vec<String>,
+    functions: &HashMap<String, usize>,
+    other_parameters: &[(String, String)],
+) -> Result<(), SemanticError> {
     for inst in block.iter() {
         match &inst.kind {
             NodeKind::WhileLoop { content, .. } => {
-                analyze_block(content, scope.clone(), functions)?;
+                analyze_block(content, scope.clone(), functions, other_parameters)?;
             }
             NodeKind::IfCondition { content, .. } => {
-                analyze_block(content, scope.clone(), functions)?;
+                analyze_block(content, scope.clone(), functions, other_parameters)?;
             }
             NodeKind::Loop { content, .. } => {
-                analyze_block(content, scope.clone(), functions)?;
+                analyze_block(content, scope.clone(), functions, other_parameters)?;
             }
             _ => {}
         }
 
+        validity::check_division_by_zero(inst)?;
+
         let used_vars = get_used_variables(inst)?;
         for var in used_vars.iter() {
             if !scope.contains(var) {
                 return Err(SemanticError::UnknownVariable(format!(
-                    "{} is not in scope{}",
+                    "{} is not in scope{}{}",
                     var,
-                    show_span_location(&inst.span)
+                    show_span_location(&inst.span),
+                    suggest_variable(var, &scope, other_parameters)
                 )));
             }
         }
@@ -62,6 +91,8 @@ fn analyze_block(block: &CodeBlock, mut scope: Vec<String>, functions: &HashMap<
             _ => {}
         }
 
+        validity::check_assertion(inst)?;
+
         let new_vars = get_new_variables(inst);
         scope.extend(new_vars.into_iter().map(|v| v.clone()));
     }
@@ -84,7 +115,9 @@ fn analyze_block(block: &CodeBlock, mut scope: Vec<String>, functions: &HashMap<
 ///
 /// # Errors
 /// * `SemanticError::UnknownVariable` - Returned if a variable is used without being declared in the current scope.
-/// * `SemanticError::InvalidOperation` - Returned if the AST contains operations that are not semantically valid.
+/// * `SemanticError::InvalidOperation` - Returned if the AST contains operations that are not semantically valid,
+///   such as a division or modulo by a literal zero, or an assertion that is not constant.
+/// * `SemanticError::AssertionFailed` - Returned if a compile-time assertion does not hold.
 ///
 /// # Example
 /// ```rust
@@ -105,11 +138,26 @@ pub fn analyze(ast: &AST) -> Result<(), SemanticError> {
         .map(|(name, func)| (name.clone(), func.parameters.len()))
         .collect::<HashMap<String, usize>>();
 
-    for (_, func) in &ast.functions {
+    // Parameters of every function along with the function they belong to, used to hint at
+    // parameters referenced outside of their function
+    let mut parameters = ast
+        .functions
+        .iter()
+        .flat_map(|(name, func)| func.parameters.iter().map(move |p| (p.clone(), name.clone())))
+        .collect::<Vec<(String, String)>>();
+    parameters.sort();
+
+    for (name, func) in &ast.functions {
         let mut in_scope = machine::prelude::get_special_variables();
         in_scope.extend(func.parameters.clone());
 
-        analyze_block(&func.content, in_scope, &function_arities)?;
+        let other_parameters = parameters
+            .iter()
+            .filter(|(_, function)| function != name)
+            .cloned()
+            .collect::<Vec<(String, String)>>();
+
+        analyze_block(&func.content, in_scope, &function_arities, &other_parameters)?;
     }
 
     Ok(())
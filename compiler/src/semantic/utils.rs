@@ -23,12 +23,30 @@ pub fn get_new_variables(node: &Box<Node>) -> Vec<&String> {
 
 // Returns all the variables used by this node and its children
 // This function is used to check if a variable is used before being declared
-pub fn get_used_variables(node: &Box<Node>) -> Result<Vec<&String>, SemanticError> {
+// Memory values are returned with their `$` prefix, as they appear in the special variables
+pub fn get_used_variables(node: &Box<Node>) -> Result<Vec<String>, SemanticError> {
     match &node.kind {
-        NodeKind::Identifier { name } => Ok(vec![name]),
+        NodeKind::Identifier { name } => Ok(vec![name.clone()]),
+        NodeKind::MemoryValue { name } => Ok(vec![format!("${}", name)]),
+        NodeKind::MemoryOffset { base, offset } => {
+            let mut vars = get_used_variables(offset)?;
+            // Arrays are not declared, only memory mapped bases can be checked
+            if matches!(base.kind, NodeKind::MemoryValue { .. }) {
+                vars.extend(get_used_variables(base)?);
+            }
+            Ok(vars)
+        }
         NodeKind::Assignment { rparam, lparam } => {
             is_valid_assignment_lparam(lparam)?;
-            get_used_variables(rparam)
+            let mut vars = get_used_variables(rparam)?;
+            // Assigning to memory does not declare it, it must exist
+            if matches!(
+                lparam.kind,
+                NodeKind::MemoryValue { .. } | NodeKind::MemoryOffset { .. }
+            ) {
+                vars.extend(get_used_variables(lparam)?);
+            }
+            Ok(vars)
         }
         NodeKind::Operation { lparam, rparam, .. } => {
             let mut vars = get_used_variables(lparam)?;
@@ -42,6 +60,15 @@ pub fn get_used_variables(node: &Box<Node>) -> Result<Vec<&String>, SemanticErro
         }
         NodeKind::WhileLoop { condition, .. } => get_used_variables(condition),
         NodeKind::IfCondition { condition, .. } => get_used_variables(condition),
+        NodeKind::Print { values } => {
+            let mut vars = vec![];
+            for value in values.iter() {
+                vars.extend(get_used_variables(value)?);
+            }
+            Ok(vars)
+        }
+        NodeKind::Return { value } => get_used_variables(value),
+        NodeKind::Assert { condition } => get_used_variables(condition),
         NodeKind::FunctionCall { parameters, .. } => {
             let mut vars = vec![];
             for param in parameters.iter() {
@@ -52,3 +79,35 @@ pub fn get_used_variables(node: &Box<Node>) -> Result<Vec<&String>, SemanticErro
         _ => Ok(vec![]),
     }
 }
+
+/// Returns the edit distance (Levenshtein) between the two names
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + if ca == *cb { 0 } else { 1 };
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Returns the candidate closest to the name, if it is close enough to likely be a typo.
+/// Up to one edit every three characters is tolerated, so that short names do not match
+/// unrelated short names.
+pub fn closest_name<'a, I>(name: &str, candidates: I) -> Option<&'a String>
+where
+    I: IntoIterator<Item = &'a String>,
+{
+    candidates
+        .into_iter()
+        .map(|candidate| (candidate, edit_distance(name, candidate)))
+        .filter(|(candidate, distance)| *distance <= name.len().max(candidate.len()) / 3)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| candidate)
+}
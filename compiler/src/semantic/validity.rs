@@ -1,4 +1,4 @@
-use crate::ast::node::{Node, NodeKind};
+use crate::ast::node::{ComparisonType, Node, NodeKind, OperationType};
 
 use super::error::SemanticError;
 use super::utils::show_span_location;
@@ -15,3 +15,103 @@ pub fn is_valid_assignment_lparam(node: &Box<Node>) -> Result<(), SemanticError>
         _ => Ok(()),
     }
 }
+
+/// Checks that the node and its children do not divide by a literal zero, which is a
+/// guaranteed crash at runtime. The code blocks of loops and conditions are not visited.
+pub fn check_division_by_zero(node: &Node) -> Result<(), SemanticError> {
+    match &node.kind {
+        NodeKind::Operation {
+            lparam,
+            rparam,
+            operation,
+        } => {
+            if matches!(operation, OperationType::Division | OperationType::Modulo)
+                && matches!(rparam.kind, NodeKind::Litteral { value: 0 })
+            {
+                return Err(SemanticError::InvalidOperation(format!(
+                    "{} by zero{}",
+                    operation,
+                    show_span_location(&node.span)
+                )));
+            }
+            check_division_by_zero(lparam)?;
+            check_division_by_zero(rparam)
+        }
+        NodeKind::Assignment { lparam, rparam } | NodeKind::Comparison { lparam, rparam, .. } => {
+            check_division_by_zero(lparam)?;
+            check_division_by_zero(rparam)
+        }
+        NodeKind::MemoryOffset { base, offset } => {
+            check_division_by_zero(base)?;
+            check_division_by_zero(offset)
+        }
+        NodeKind::Print { values: nodes } | NodeKind::FunctionCall { parameters: nodes, .. } => {
+            nodes.iter().try_for_each(|node| check_division_by_zero(node))
+        }
+        NodeKind::Return { value } | NodeKind::Assert { condition: value } => {
+            check_division_by_zero(value)
+        }
+        NodeKind::WhileLoop { condition, .. } | NodeKind::IfCondition { condition, .. } => {
+            check_division_by_zero(condition)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Evaluates the node at compile time, returning `None` if its value is only known at runtime
+/// or if the computation overflows.
+pub fn constant_value(node: &Node) -> Option<i32> {
+    match &node.kind {
+        NodeKind::Litteral { value } => Some(*value),
+        NodeKind::Operation {
+            lparam,
+            rparam,
+            operation,
+        } => {
+            let (lvalue, rvalue) = (constant_value(lparam)?, constant_value(rparam)?);
+            match operation {
+                OperationType::Addition => lvalue.checked_add(rvalue),
+                OperationType::Substraction => lvalue.checked_sub(rvalue),
+                OperationType::Multiplication => lvalue.checked_mul(rvalue),
+                OperationType::Division => lvalue.checked_div(rvalue),
+                OperationType::Modulo => lvalue.checked_rem(rvalue),
+            }
+        }
+        NodeKind::Comparison {
+            lparam,
+            rparam,
+            comparison,
+        } => {
+            let (lvalue, rvalue) = (constant_value(lparam)?, constant_value(rparam)?);
+            let holds = match comparison {
+                ComparisonType::GT => lvalue > rvalue,
+                ComparisonType::GE => lvalue >= rvalue,
+                ComparisonType::EQ => lvalue == rvalue,
+                ComparisonType::LE => lvalue <= rvalue,
+                ComparisonType::LT => lvalue < rvalue,
+                ComparisonType::DIFF => lvalue != rvalue,
+            };
+            Some(holds as i32)
+        }
+        _ => None,
+    }
+}
+
+/// Checks that the condition of an assertion is constant and holds. Any other node is accepted.
+pub fn check_assertion(node: &Node) -> Result<(), SemanticError> {
+    let NodeKind::Assert { condition } = &node.kind else {
+        return Ok(());
+    };
+
+    match constant_value(condition) {
+        None => Err(SemanticError::InvalidOperation(format!(
+            "assertion must be constant{}",
+            show_span_location(&node.span)
+        ))),
+        Some(0) => Err(SemanticError::AssertionFailed(format!(
+            "assertion does not hold{}",
+            show_span_location(&node.span)
+        ))),
+        Some(_) => Ok(()),
+    }
+}
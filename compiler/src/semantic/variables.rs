@@ -0,0 +1,48 @@
+use std::collections::BTreeSet;
+
+use crate::ast::node::{CodeBlock, NodeKind};
+use crate::ast::Function;
+
+use super::utils::{get_new_variables, get_used_variables};
+
+/// The kind of a variable referenced by a function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariableKind {
+    Local,     // Declared in the function's body
+    Parameter, // Parameter of the function
+    Special,   // Memory mapped special variable ($Velocity, ...)
+}
+
+/// Returns the variables (locals, parameters and special variables) read or written by
+/// the function, including in its nested blocks. Special variables keep their `$` prefix.
+pub fn referenced_variables(function: &Function) -> BTreeSet<String> {
+    let mut variables = BTreeSet::new();
+    collect_variables(&function.content, &mut variables);
+    variables
+}
+
+/// Returns the kind of a variable referenced by the function
+pub fn variable_kind(function: &Function, name: &str) -> VariableKind {
+    if name.starts_with('$') {
+        VariableKind::Special
+    } else if function.parameters.iter().any(|parameter| parameter == name) {
+        VariableKind::Parameter
+    } else {
+        VariableKind::Local
+    }
+}
+
+fn collect_variables(block: &CodeBlock, variables: &mut BTreeSet<String>) {
+    for node in block.iter() {
+        // Invalid nodes are reported by the semantic analysis, they are skipped here
+        variables.extend(get_used_variables(node).unwrap_or_default());
+        variables.extend(get_new_variables(node).into_iter().cloned());
+
+        match &node.kind {
+            NodeKind::WhileLoop { content, .. }
+            | NodeKind::IfCondition { content, .. }
+            | NodeKind::Loop { content } => collect_variables(content, variables),
+            _ => {}
+        }
+    }
+}
@@ -3,6 +3,43 @@
 
 use afgcompiler::lexer::parse_source;
 use afgcompiler::ast::AST;
+use afgcompiler::prelude::*;
+use afgcompiler::semantic::{referenced_variables, variable_kind, VariableKind};
+
+use machine::prelude::{parse, DebugInfo, VirtualMachine};
+
+// ========================================
+// Helper Functions
+// ========================================
+
+/// Compiles the given source code down to machine instructions, along with their debug information
+fn compile(code: &str) -> Result<(Vec<machine::prelude::Instruction>, DebugInfo), String> {
+    let final_code = compile_with(code, |_| Ok(()))?;
+    let (resolved, debug_info) = resolve_labels_with_debug_info(final_code)?;
+    let text = resolved
+        .iter()
+        .map(|i| format!("{}", i))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    Ok((parse(text).map_err(|e| e.to_string())?, debug_info))
+}
+
+/// Compiles and runs the given source code, returning the outputs of the machine
+fn compile_and_run(code: &str) -> Result<Vec<String>, String> {
+    let (instructions, debug_info) = compile(code)?;
+    let mut machine = VirtualMachine::new();
+    machine.load_program_with_debug_info(instructions, &debug_info)?;
+
+    let mut outputs = vec![];
+    while !machine.has_completed() {
+        machine.tick()?;
+        if let Some(output) = machine.get_current_output(true) {
+            outputs.push(output);
+        }
+    }
+    Ok(outputs)
+}
 
 // ========================================
 // Complete Program Integration Tests
@@ -395,6 +432,169 @@ fn test_function_calls_with_various_arguments() {
 // Error Recovery Tests
 // ========================================
 
+#[test]
+fn test_unknown_special_variable_suggests_name() {
+    let code = "fn main() { set $VelocityX[0] = 10; }";
+    let ast = AST::parse(code).unwrap();
+
+    let error = analyze(&ast).map_err(|e| e.to_string()).unwrap_err();
+    assert!(error.contains("$VelocityX is not in scope"), "{}", error);
+    assert!(error.contains("did you mean `$Velocity`?"), "{}", error);
+}
+
+#[test]
+fn test_parameter_of_other_function_suggested() {
+    let code = r#"
+        fn move(speed) {
+            set $Velocity[1] = speed;
+        }
+
+        fn main() {
+            set result = move(speed);
+        }
+    "#;
+    let ast = AST::parse(code).unwrap();
+
+    let error = analyze(&ast).map_err(|e| e.to_string()).unwrap_err();
+    assert!(
+        error.contains("`speed` is a parameter of function move, not of this function"),
+        "{}",
+        error
+    );
+    assert!(!error.contains("did you mean"), "{}", error);
+}
+
+#[test]
+fn test_misspelled_parameter_of_other_function_suggested() {
+    let code = r#"
+        fn move(speed) {
+            set $Velocity[1] = speed;
+        }
+
+        fn main() {
+            set result = move(sped);
+        }
+    "#;
+    let ast = AST::parse(code).unwrap();
+
+    let error = analyze(&ast).map_err(|e| e.to_string()).unwrap_err();
+    assert!(
+        error.contains("did you mean `speed` (parameter of function move)?"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn test_unknown_variable_without_close_match() {
+    let code = r#"
+        fn helper(a) {
+            return a;
+        }
+
+        fn main() {
+            set x = 1;
+            set y = z + x;
+        }
+    "#;
+    let ast = AST::parse(code).unwrap();
+
+    let error = analyze(&ast).map_err(|e| e.to_string()).unwrap_err();
+    assert!(error.contains("z is not in scope"), "{}", error);
+    assert!(!error.contains("did you mean"), "{}", error);
+}
+
+#[test]
+fn test_undeclared_variable_in_print_rejected() {
+    let code = "fn main() {\n    set x = 1;\n    print x, undeclared;\n}";
+    let ast = AST::parse(code).unwrap();
+
+    let error = analyze(&ast).map_err(|e| e.to_string()).unwrap_err();
+    assert!(error.contains("undeclared is not in scope"), "{}", error);
+}
+
+#[test]
+fn test_undeclared_variable_in_return_rejected() {
+    let code = "fn helper() {\n    return undeclared;\n}\n\nfn main() {\n    set x = helper();\n}";
+    let ast = AST::parse(code).unwrap();
+
+    let error = analyze(&ast).map_err(|e| e.to_string()).unwrap_err();
+    assert!(error.contains("undeclared is not in scope"), "{}", error);
+}
+
+#[test]
+fn test_undeclared_variable_in_memory_offset_rejected() {
+    let code = "fn main() {\n    set $Velocity[index] = 1;\n}";
+    let ast = AST::parse(code).unwrap();
+
+    let error = analyze(&ast).map_err(|e| e.to_string()).unwrap_err();
+    assert!(error.contains("index is not in scope"), "{}", error);
+}
+
+#[test]
+fn test_function_referenced_variables() {
+    let code = r#"
+        fn steer(speed, unused) {
+            set turn = speed * 2;
+            while turn > 0 {
+                set $Velocity[0] = turn;
+                set turn = turn - 1;
+            }
+        }
+    "#;
+    let ast = AST::parse(code).unwrap();
+    let function = &ast.functions["steer"];
+
+    let variables = referenced_variables(function);
+    assert_eq!(
+        variables.iter().map(String::as_str).collect::<Vec<&str>>(),
+        vec!["$Velocity", "speed", "turn"]
+    );
+
+    assert_eq!(variable_kind(function, "speed"), VariableKind::Parameter);
+    assert_eq!(variable_kind(function, "turn"), VariableKind::Local);
+    assert_eq!(variable_kind(function, "$Velocity"), VariableKind::Special);
+}
+
+#[test]
+fn test_division_by_literal_zero_rejected() {
+    let code = "fn main() {\n    set y = 4;\n    set x = y / 0;\n}";
+
+    let error = compile(code).unwrap_err();
+    assert!(error.contains("Invalid Operation: Division by zero"), "{}", error);
+    assert!(error.contains("at line 3 column 15"), "{}", error);
+}
+
+#[test]
+fn test_modulo_by_literal_zero_rejected() {
+    let code = r#"
+        fn main() {
+            set y = 4;
+            if y > 0 {
+                set r = y % 0;
+            }
+        }
+    "#;
+
+    let error = compile(code).unwrap_err();
+    assert!(error.contains("Invalid Operation: Modulo by zero"), "{}", error);
+    assert!(error.contains("at line 5"), "{}", error);
+}
+
+#[test]
+fn test_division_by_non_zero_literal_compiles() {
+    let code = r#"
+        fn main() {
+            set y = 9;
+            set x = y / 4;
+            set r = y % 4;
+            print x, r;
+        }
+    "#;
+
+    assert_eq!(compile_and_run(code), Ok(vec!["2 1".to_string()]));
+}
+
 #[test]
 fn test_invalid_syntax_produces_error() {
     let code = "fn main() { syntax error here }";
@@ -650,3 +850,152 @@ fn test_deeply_nested_loops() {
 
     assert!(ast.is_ok());
 }
+
+// ========================================
+// Execution Tests
+// ========================================
+
+#[test]
+fn test_function_call_runs() {
+    let code = r#"
+        fn double(x) {
+            set y = x * 2;
+            return y;
+        }
+
+        fn main() {
+            set result = double(21);
+            print result;
+        }
+    "#;
+
+    let (_, debug_info) = compile(code).unwrap();
+    assert_eq!(debug_info.function_entries().len(), 2);
+    assert_eq!(compile_and_run(code), Ok(vec!["42".to_string()]));
+}
+
+#[test]
+fn test_call_to_non_function_label_rejected() {
+    let code = vec![
+        PASMInstruction::new_label("function_main_label".to_string()),
+        PASMInstruction::new_label("loop_label".to_string()),
+        PASMInstruction::new(
+            "call".to_string(),
+            vec![afgcompiler::pasm::OperandType::Identifier {
+                name: "loop_label".to_string(),
+            }],
+        ),
+        PASMInstruction::new("halt".to_string(), vec![]),
+    ];
+
+    let result = resolve_labels(code);
+    assert!(result.is_err());
+    assert!(result.unwrap_err().contains("not a function entry"));
+}
+
+#[test]
+fn test_print_multiple_values() {
+    let code = r#"
+        fn main() {
+            set a = 4;
+            set b = 2;
+            set $Velocity[1] = 7;
+            print a, b, $Velocity[1];
+            print a;
+        }
+    "#;
+
+    assert_eq!(
+        compile_and_run(code),
+        Ok(vec!["4 2 7".to_string(), "4".to_string()])
+    );
+}
+
+#[test]
+fn test_scan_all_rays() {
+    let code = r#"
+        fn main() {
+            set i = 0;
+            while i < RAY_COUNT {
+                set $RayDist[i] = i * 10;
+                set i = i + 1;
+            }
+
+            set i = 0;
+            set max = 0;
+            set scanned = 0;
+            while i < RAY_COUNT {
+                if $RayDist[i] > max {
+                    set max = $RayDist[i];
+                }
+                set scanned = scanned + 1;
+                set i = i + 1;
+            }
+            print max, scanned;
+        }
+    "#;
+
+    let ray_count = machine::prelude::RAY_COUNT;
+    assert_eq!(
+        compile_and_run(code),
+        Ok(vec![format!("{} {}", (ray_count - 1) * 10, ray_count)])
+    );
+}
+
+#[test]
+fn test_true_assertion_emits_no_code() {
+    let with_assert = "fn main() {\n    assert RAY_COUNT == 7;\n    set x = 1;\n    print x;\n}";
+    let without_assert = "fn main() {\n    set x = 1;\n    print x;\n}";
+
+    let (instructions, _) = compile(with_assert).unwrap();
+    let (expected, _) = compile(without_assert).unwrap();
+    assert_eq!(instructions, expected);
+    assert_eq!(compile_and_run(with_assert), Ok(vec!["1".to_string()]));
+}
+
+#[test]
+fn test_false_assertion_rejected() {
+    let code = "fn main() {\n    assert RAY_COUNT == 8;\n}";
+
+    let error = compile(code).unwrap_err();
+    assert!(error.contains("Assertion Failed: assertion does not hold"), "{}", error);
+    assert!(error.contains("at line 2 column 5"), "{}", error);
+}
+
+#[test]
+fn test_arithmetic_assertion_folded() {
+    let without_assert = "fn main() {\n    print 1;\n}";
+    let (expected, _) = compile(without_assert).unwrap();
+
+    for condition in ["RAY_COUNT + 1 == 8", "RAY_COUNT * 2 == 14", "(RAY_COUNT - 1) % 4 != 0"] {
+        let code = format!("fn main() {{\n    assert {};\n    print 1;\n}}", condition);
+        let (instructions, _) = compile(&code).unwrap();
+        assert_eq!(instructions, expected, "{}", condition);
+    }
+}
+
+#[test]
+fn test_false_arithmetic_assertion_rejected() {
+    let code = "fn main() {\n    assert RAY_COUNT * 2 == 15;\n}";
+
+    let error = compile(code).unwrap_err();
+    assert!(error.contains("Assertion Failed: assertion does not hold"), "{}", error);
+    assert!(error.contains("at line 2 column 5"), "{}", error);
+}
+
+#[test]
+fn test_assertion_dividing_by_zero_rejected() {
+    let code = "fn main() {\n    assert 5 / 0 == 1;\n}";
+
+    let error = compile(code).unwrap_err();
+    assert!(error.contains("Invalid Operation: Division by zero"), "{}", error);
+}
+
+#[test]
+fn test_non_constant_assertion_rejected() {
+    let code = "fn main() {\n    set x = 7;\n    assert x == RAY_COUNT;\n}";
+
+    let error = compile(code).unwrap_err();
+    assert!(error.contains("assertion must be constant"), "{}", error);
+    assert!(error.contains("at line 3 column 5"), "{}", error);
+}
@@ -0,0 +1,14 @@
+#[cfg(feature = "loader")]
+mod loader;
+mod map;
+mod scenario;
+
+#[cfg(test)]
+mod tests;
+
+pub mod prelude {
+    #[cfg(feature = "loader")]
+    pub use super::loader::*;
+    pub use super::map::*;
+    pub use super::scenario::*;
+}
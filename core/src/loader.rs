@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use afgcompiler::prelude::compile;
+use machine::prelude::{parse, HeadlessDriver, Instruction, VirtualMachine};
+
+use super::map::{Map, SpawnPoint};
+use super::scenario::{MapSource, ProgramSource, Scenario};
+
+/// A bot of a loaded scenario, ready to run
+#[derive(Debug, Clone)]
+pub struct LoadedBot {
+    pub spawn_point: SpawnPoint,
+    pub program: Vec<Instruction>,
+}
+
+impl LoadedBot {
+    /// Returns a headless driver running the bot's program from its spawn point
+    pub fn driver(&self) -> HeadlessDriver {
+        HeadlessDriver::new(VirtualMachine::new().with_program(self.program.clone()))
+            .with_position(self.spawn_point.x, self.spawn_point.y)
+            .with_rotation(self.spawn_point.rotation)
+    }
+}
+
+/// A scenario whose map is resolved and validated and whose programs are compiled
+#[derive(Debug, Clone)]
+pub struct LoadedScenario {
+    pub map: Map,
+    pub bots: Vec<LoadedBot>,
+}
+
+impl Scenario {
+    /// Reads the scenario from JSON, resolves its map among the given maps (by id) and
+    /// validates it, then compiles the programs of the bots
+    pub fn load(json: &str, maps: &HashMap<String, Map>) -> Result<LoadedScenario, String> {
+        let scenario = Self::from_json(json)?;
+
+        let map = match scenario.map {
+            MapSource::Inline(map) => map,
+            MapSource::Id(id) => maps
+                .get(&id)
+                .cloned()
+                .ok_or(format!("Unknown map {}", id))?,
+        };
+        validate_map(&map)?;
+
+        let bots = scenario
+            .bots
+            .iter()
+            .enumerate()
+            .map(|(index, bot)| {
+                let spawn_point = *map.spawn_points.get(bot.spawn).ok_or(format!(
+                    "Bot {} uses spawn point {} but map {} only has {} spawn points",
+                    index,
+                    bot.spawn,
+                    map.title,
+                    map.spawn_points.len()
+                ))?;
+
+                let asmfg = match &bot.program {
+                    ProgramSource::Source(source) => compile(source).map_err(|e| {
+                        format!("Unable to compile the program of bot {}: {}", index, e)
+                    })?,
+                    ProgramSource::Compiled(asmfg) => asmfg.clone(),
+                };
+                let program = parse(asmfg)
+                    .map_err(|e| format!("Invalid program for bot {}: {}", index, e))?;
+
+                Ok(LoadedBot {
+                    spawn_point,
+                    program,
+                })
+            })
+            .collect::<Result<Vec<LoadedBot>, String>>()?;
+
+        Ok(LoadedScenario { map, bots })
+    }
+}
+
+/// Checks that the map has a size and that its spawn points are on it
+fn validate_map(map: &Map) -> Result<(), String> {
+    if map.size.0 <= 0 || map.size.1 <= 0 || map.tile_size <= 0 {
+        return Err(format!("Map {} has an empty size", map.title));
+    }
+    if map.size.0.checked_mul(map.tile_size).is_none()
+        || map.size.1.checked_mul(map.tile_size).is_none()
+    {
+        return Err(format!("Map {} is too large", map.title));
+    }
+
+    let (width, height) = map.world_size();
+    for (index, point) in map.spawn_points.iter().enumerate() {
+        if point.x < 0.0 || point.y < 0.0 || point.x > width || point.y > height {
+            return Err(format!(
+                "Spawn point {} of map {} is outside of the map",
+                index, map.title
+            ));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+/// Amount of teams fighting on a map
+pub const TEAM_COUNT: u8 = 2;
+
+/// An area where the bots of a team spawn randomly, in tiles (x, y, width, height)
+pub type SpawnArea = (i32, i32, i32, i32);
+
+/// A wall of the map, positions and dimensions are in tiles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Wall {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+/// A place where a bot of the given team spawns. The position is in world units and the
+/// rotation in radians, a rotation of 0 makes the bot face up (towards positive y).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SpawnPoint {
+    pub x: f32,
+    pub y: f32,
+    pub rotation: f32,
+    pub team: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "bevy", derive(bevy::asset::Asset, bevy::reflect::TypePath))]
+pub struct Map {
+    pub title: String,
+    pub size: (i32, i32),
+    pub tile_size: i32,
+    pub spawn_places: (SpawnArea, SpawnArea),
+    #[serde(default)]
+    pub spawn_points: Vec<SpawnPoint>,
+    pub walls: Vec<Wall>,
+}
+
+impl Map {
+    /// Returns the size of the map in world units
+    pub fn world_size(&self) -> (f32, f32) {
+        // Multiplied as floats, the size comes from user files and may overflow an i32
+        (
+            self.size.0 as f32 * self.tile_size as f32,
+            self.size.1 as f32 * self.tile_size as f32,
+        )
+    }
+}
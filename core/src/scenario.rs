@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use super::map::Map;
+
+/// The map a scenario is played on
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MapSource {
+    Inline(Map),
+    Id(String), // Id of a map known by the loader
+}
+
+/// The program a bot runs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProgramSource {
+    Source(String),   // AFG source code, compiled when the scenario is loaded
+    Compiled(String), // AsmFG code
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BotEntry {
+    pub spawn: usize, // Index of the map's spawn point the bot starts at
+    pub program: ProgramSource,
+}
+
+/// A map along with the bots fighting on it, stored as JSON
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Scenario {
+    pub map: MapSource,
+    pub bots: Vec<BotEntry>,
+}
+
+impl Scenario {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Invalid scenario: {}", e))
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+}
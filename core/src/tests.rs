@@ -0,0 +1,163 @@
+#[cfg(feature = "loader")]
+use std::collections::HashMap;
+
+#[cfg(feature = "loader")]
+use machine::prelude::StepOutcome;
+
+use super::prelude::*;
+
+// ========================================
+// Helper Functions
+// ========================================
+
+fn arena() -> Map {
+    Map {
+        title: "arena".to_string(),
+        size: (10, 5),
+        tile_size: 100,
+        spawn_places: ((0, 0, 1, 1), (9, 4, 1, 1)),
+        spawn_points: vec![
+            SpawnPoint {
+                x: 150.0,
+                y: 250.0,
+                rotation: 0.0,
+                team: 0,
+            },
+            SpawnPoint {
+                x: 850.0,
+                y: 250.0,
+                rotation: 3.0,
+                team: 1,
+            },
+        ],
+        walls: vec![Wall {
+            x: 0,
+            y: 0,
+            width: 10,
+            height: 1,
+        }],
+    }
+}
+
+fn scenario(map: MapSource, spawns: &[usize]) -> Scenario {
+    Scenario {
+        map,
+        bots: spawns
+            .iter()
+            .map(|spawn| BotEntry {
+                spawn: *spawn,
+                program: ProgramSource::Source("fn main() { print 7; }".to_string()),
+            })
+            .collect(),
+    }
+}
+
+// ========================================
+// Scenario Tests
+// ========================================
+
+#[test]
+fn test_scenario_round_trip() {
+    let mut scenario = scenario(MapSource::Inline(arena()), &[0]);
+    scenario.bots.push(BotEntry {
+        spawn: 1,
+        program: ProgramSource::Compiled("print #3\nhalt".to_string()),
+    });
+
+    let json = scenario.to_json().unwrap();
+    assert_eq!(Scenario::from_json(&json), Ok(scenario));
+}
+
+#[cfg(feature = "loader")]
+#[test]
+fn test_scenario_load_inline_map() {
+    let mut scenario = scenario(MapSource::Inline(arena()), &[0]);
+    scenario.bots.push(BotEntry {
+        spawn: 1,
+        program: ProgramSource::Compiled("print #3\nhalt".to_string()),
+    });
+
+    let loaded = Scenario::load(&scenario.to_json().unwrap(), &HashMap::new()).unwrap();
+    assert_eq!(loaded.map, arena());
+    assert_eq!(loaded.bots.len(), 2);
+    assert_eq!(loaded.bots[0].spawn_point, arena().spawn_points[0]);
+    assert_eq!(loaded.bots[1].spawn_point, arena().spawn_points[1]);
+}
+
+#[cfg(feature = "loader")]
+#[test]
+fn test_scenario_compiles_sources() {
+    let json = scenario(MapSource::Inline(arena()), &[1])
+        .to_json()
+        .unwrap();
+    let loaded = Scenario::load(&json, &HashMap::new()).unwrap();
+
+    let mut driver = loaded.bots[0].driver();
+    assert_eq!(driver.position, (850.0, 250.0));
+
+    let outputs = (0..10)
+        .filter_map(|_| match driver.step() {
+            StepOutcome::Printed(output) => Some(output),
+            _ => None,
+        })
+        .collect::<Vec<String>>();
+    assert_eq!(outputs, vec!["7".to_string()]);
+}
+
+#[cfg(feature = "loader")]
+#[test]
+fn test_scenario_map_by_id() {
+    let json = scenario(MapSource::Id("arena".to_string()), &[0, 1])
+        .to_json()
+        .unwrap();
+    let maps = HashMap::from([("arena".to_string(), arena())]);
+
+    let loaded = Scenario::load(&json, &maps).unwrap();
+    assert_eq!(loaded.map, arena());
+
+    let error = Scenario::load(&json, &HashMap::new()).unwrap_err();
+    assert_eq!(error, "Unknown map arena");
+}
+
+#[cfg(feature = "loader")]
+#[test]
+fn test_scenario_rejects_missing_spawn_point() {
+    let json = scenario(MapSource::Inline(arena()), &[0, 2])
+        .to_json()
+        .unwrap();
+
+    let error = Scenario::load(&json, &HashMap::new()).unwrap_err();
+    assert_eq!(
+        error,
+        "Bot 1 uses spawn point 2 but map arena only has 2 spawn points"
+    );
+}
+
+#[cfg(feature = "loader")]
+#[test]
+fn test_scenario_rejects_oversized_map() {
+    let map = Map {
+        size: (50000, 50000),
+        tile_size: 50000,
+        spawn_points: vec![],
+        ..arena()
+    };
+    let json = scenario(MapSource::Inline(map), &[]).to_json().unwrap();
+
+    let error = Scenario::load(&json, &HashMap::new()).unwrap_err();
+    assert_eq!(error, "Map arena is too large");
+}
+
+#[cfg(feature = "loader")]
+#[test]
+fn test_scenario_rejects_invalid_program() {
+    let mut scenario = scenario(MapSource::Inline(arena()), &[0]);
+    scenario.bots[0].program = ProgramSource::Source("fn main() { print x; }".to_string());
+
+    let error = Scenario::load(&scenario.to_json().unwrap(), &HashMap::new()).unwrap_err();
+    assert!(
+        error.starts_with("Unable to compile the program of bot 0"),
+        "{}",
+        error
+    );
+}
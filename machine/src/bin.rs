@@ -8,7 +8,7 @@ use ratatui::{prelude::Backend, Terminal};
 use colog;
 use log::{error, info};
 
-use machine::prelude::{Program, VirtualMachine};
+use machine::prelude::{Program, StepOutcome, VirtualMachine};
 
 mod app;
 mod blocks;
@@ -60,16 +60,17 @@ fn main() -> Result<(), String> {
         info!("Starting execution");
         println!("Output:");
         loop {
-            if let Err(e) = machine.tick() {
-                error!("Machine encountered an error: {}", e);
-                break Err(e);
-            }
-            if let Some(output) = machine.get_current_output(true) {
-                println!("> {}", output);
-            }
-            if machine.has_completed() {
-                info!("Machine has completed its execution");
-                break Ok(());
+            match machine.step() {
+                StepOutcome::Ran => {}
+                StepOutcome::Printed(output) => println!("> {}", output),
+                StepOutcome::Halted(e) => {
+                    error!("Machine encountered an error: {}", e);
+                    break Err(e);
+                }
+                StepOutcome::Completed => {
+                    info!("Machine has completed its execution");
+                    break Ok(());
+                }
             }
         }
     }
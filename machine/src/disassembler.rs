@@ -0,0 +1,218 @@
+use std::collections::BTreeMap;
+
+use super::enums::{register_to_string, OpCodes, OperandType};
+use super::Instruction;
+
+/// Width of the mnemonic column in annotated listings (longest mnemonic + 1)
+const MNEMONIC_WIDTH: usize = 9;
+/// Width of the operands column in annotated listings, before the comment column
+const OPERANDS_WIDTH: usize = 24;
+
+/// Debug information attached to a program.
+/// Both maps are keyed by instruction address (index in the program).
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DebugInfo {
+    pub labels: BTreeMap<usize, Vec<String>>, // Labels pointing at an address
+    pub source_lines: BTreeMap<usize, usize>, // Source line that produced the instruction at an address
+}
+
+impl DebugInfo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a label pointing at the given address
+    pub fn add_label<S: AsRef<str>>(&mut self, address: usize, label: S) {
+        self.labels
+            .entry(address)
+            .or_default()
+            .push(label.as_ref().to_string());
+    }
+
+    /// Registers the source line that produced the instruction at the given address
+    pub fn add_source_line(&mut self, address: usize, line: usize) {
+        self.source_lines.insert(address, line);
+    }
+
+    /// Returns the addresses pointed at by a function label, that is the addresses a `call`
+    /// may jump to
+    pub fn function_entries(&self) -> Vec<usize> {
+        self.labels
+            .iter()
+            .filter(|(_, labels)| labels.iter().any(is_function_label))
+            .map(|(address, _)| *address)
+            .collect()
+    }
+
+    /// Checks that every `call` with a literal target jumps to a function entry. Functions set up
+    /// their frame in their first instructions, calling into the middle of a function breaks the
+    /// frame and makes `ret` misbehave. If no function entry is known, nothing is checked.
+    pub fn check_call_targets(&self, instructions: &[Instruction]) -> Result<(), String> {
+        let entries = self.function_entries();
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        for (address, instruction) in instructions.iter().enumerate() {
+            if let (OpCodes::CALL, OperandType::Literal { value }) =
+                (instruction.opcode, instruction.operand_1)
+            {
+                // Call targets are relative to the call instruction, they may point far outside
+                // of the program
+                let target = address as i64 + value as i64;
+                if !usize::try_from(target).is_ok_and(|target| entries.contains(&target)) {
+                    return Err(format!(
+                        "call at address {} targets address {} which is not a function entry",
+                        address, target
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns true if the label marks the entry of a function (`function_<name>_label`)
+pub fn is_function_label<S: AsRef<str>>(label: S) -> bool {
+    let label = label.as_ref();
+    label.len() > "function__label".len()
+        && label.starts_with("function_")
+        && label.ends_with("_label")
+}
+
+/// Returns the mnemonic of the opcode, as understood by the parser
+fn mnemonic(opcode: OpCodes) -> &'static str {
+    match opcode {
+        OpCodes::MOV => "mov",
+        OpCodes::STORE => "store",
+        OpCodes::LOAD => "load",
+        OpCodes::ADD => "add",
+        OpCodes::SUB => "sub",
+        OpCodes::MUL => "mul",
+        OpCodes::DIV => "div",
+        OpCodes::MOD => "mod",
+        OpCodes::CMP => "cmp",
+        OpCodes::JMP => "jmp",
+        OpCodes::JZ => "jz",
+        OpCodes::JNZ => "jnz",
+        OpCodes::JN => "jn",
+        OpCodes::JP => "jp",
+        OpCodes::CALL => "call",
+        OpCodes::RET => "ret",
+        OpCodes::POP => "pop",
+        OpCodes::PUSH => "push",
+        OpCodes::PRINT => "print",
+        OpCodes::PRINTC => "printc",
+        OpCodes::SAVEREGS => "saveregs",
+        OpCodes::LOADREGS => "loadregs",
+        OpCodes::HLT => "halt",
+    }
+}
+
+/// Returns the operand written the way the parser expects it, None if there is no operand
+fn operand_to_asm(operand: &OperandType) -> Option<String> {
+    match operand {
+        OperandType::Literal { value } => Some(format!("#{}", value)),
+        OperandType::Register { idx } => Some(format!("'{}", register_to_string(*idx))),
+        OperandType::StackValue {
+            base_register,
+            addition,
+            offset,
+        } => Some(format!(
+            "['{} {} {}]",
+            register_to_string(*base_register),
+            if *addition { '+' } else { '-' },
+            offset
+        )),
+        OperandType::MemoryOffset {
+            base_register,
+            addition,
+            offset_register,
+        } => Some(format!(
+            "{{'{} {} '{}}}",
+            register_to_string(*base_register),
+            if *addition { '+' } else { '-' },
+            register_to_string(*offset_register)
+        )),
+        OperandType::None => None,
+    }
+}
+
+/// Returns the instruction as a single parseable line
+fn instruction_to_asm(instruction: &Instruction) -> String {
+    [
+        Some(mnemonic(instruction.opcode).to_string()),
+        operand_to_asm(&instruction.operand_1),
+        operand_to_asm(&instruction.operand_2),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<String>>()
+    .join(" ")
+}
+
+/// Returns the instruction with its mnemonic and operands aligned in columns
+fn instruction_to_listing(instruction: &Instruction) -> String {
+    let operands = [
+        operand_to_asm(&instruction.operand_1),
+        operand_to_asm(&instruction.operand_2),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<String>>()
+    .join(" ");
+
+    format!(
+        "{:<mnemonic_width$}{}",
+        mnemonic(instruction.opcode),
+        operands,
+        mnemonic_width = MNEMONIC_WIDTH
+    )
+    .trim_end()
+    .to_string()
+}
+
+/// Disassembles the instructions back to text.
+///
+/// Without debug information, the output contains one instruction per line and can be fed
+/// back to the parser, as long as the instructions only use registers the parser accepts
+/// (which is the case of any program produced by the parser). The instruction pointer and
+/// unknown register indices are written as `'CIP` and `'XXX`, which the parser rejects.
+/// With debug information, the output is an annotated listing meant for
+/// human review: labels are written on their own line before the instruction they point at,
+/// and instructions are followed by a comment with the source line they come from.
+pub fn disassemble(instructions: &[Instruction], debug_info: Option<&DebugInfo>) -> String {
+    let Some(debug_info) = debug_info else {
+        return instructions
+            .iter()
+            .map(instruction_to_asm)
+            .collect::<Vec<String>>()
+            .join("\n");
+    };
+
+    let mut lines = vec![];
+    for (address, instruction) in instructions.iter().enumerate() {
+        if let Some(labels) = debug_info.labels.get(&address) {
+            lines.extend(labels.iter().map(|label| format!("{}:", label)));
+        }
+
+        let listing = format!("{:04X}    {}", address, instruction_to_listing(instruction));
+        lines.push(match debug_info.source_lines.get(&address) {
+            Some(line) => format!(
+                "{:<width$}; line {}",
+                listing,
+                line,
+                width = 8 + MNEMONIC_WIDTH + OPERANDS_WIDTH
+            ),
+            None => listing,
+        });
+    }
+
+    // Labels pointing past the last instruction (e.g. the exit label of a trailing loop)
+    for (_, labels) in debug_info.labels.range(instructions.len()..) {
+        lines.extend(labels.iter().map(|label| format!("{}:", label)));
+    }
+
+    lines.join("\n")
+}
@@ -0,0 +1,61 @@
+use super::enums::StepOutcome;
+use super::machine::VirtualMachine;
+
+/// Duration of a step, in seconds (the game runs at 60 ticks per second)
+pub const DEFAULT_TIME_STEP: f32 = 1.0 / 60.0;
+
+/// Runs a virtual machine along with the minimal physics state of a bot, without bevy.
+/// Each step does what the game's systems do in a frame: tick the machine, exchange the
+/// memory mapped properties with the bot and update the rays, then moves the bot.
+pub struct HeadlessDriver {
+    pub machine: VirtualMachine,
+    pub position: (f32, f32),
+    pub rotation: f32,          // In radians
+    pub velocity: (f32, f32),   // In world units per second
+    pub angular_velocity: f32,  // In radians per second
+    pub rays: Vec<Option<f32>>, // Distance of the obstacle hit by each ray, None if nothing was hit
+    pub time_step: f32,         // Duration of a step in seconds
+}
+
+impl HeadlessDriver {
+    pub fn new(machine: VirtualMachine) -> Self {
+        Self {
+            machine,
+            position: (0.0, 0.0),
+            rotation: 0.0,
+            velocity: (0.0, 0.0),
+            angular_velocity: 0.0,
+            rays: vec![],
+            time_step: DEFAULT_TIME_STEP,
+        }
+    }
+
+    pub fn with_position(mut self, x: f32, y: f32) -> Self {
+        self.position = (x, y);
+        self
+    }
+
+    pub fn with_rotation(mut self, rotation: f32) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Runs one step of the bot. A machine that halted no longer drives the bot, its physics
+    /// state is left untouched.
+    pub fn step(&mut self) -> StepOutcome {
+        let outcome = self.machine.step();
+        if matches!(outcome, StepOutcome::Halted(_)) {
+            return outcome;
+        }
+
+        (self.velocity, self.angular_velocity) =
+            self.machine.sync_mmp(self.position, self.rotation);
+        self.machine.sync_rays(&self.rays);
+
+        self.position.0 += self.velocity.0 * self.time_step;
+        self.position.1 += self.velocity.1 * self.time_step;
+        self.rotation += self.angular_velocity * self.time_step;
+
+        outcome
+    }
+}
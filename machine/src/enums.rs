@@ -1,6 +1,7 @@
 use std::fmt;
 
-fn register_to_string(index: usize) -> String {
+/// Returns the name of the register at the given index, `XXX` if there is none
+pub(crate) fn register_to_string(index: usize) -> String {
     match index {
         i if i == Registers::GPA as usize => "GPA".to_string(),
         i if i == Registers::GPB as usize => "GPB".to_string(),
@@ -120,6 +121,9 @@ pub enum OpCodes {
     POP, // Pops a value from the stack into <r<op1>>
     PUSH, // Pushes to the stack the value of <r<op1>>
     PRINT, // Prints the value of <r<op1>> to the console
+    PRINTC, // Appends the value of <r<op1>> to the current output line, printed along with the next PRINT
+    SAVEREGS, // Stores GPA..GPD in memory, from address #<r<op1>> onwards
+    LOADREGS, // Loads GPA..GPD from memory, from address #<r<op1>> onwards
     HLT, // Halts the machine
 }
 
@@ -151,6 +155,19 @@ impl fmt::Display for MachineStatus {
     }
 }
 
+/// What happened during a step of the machine
+/// Ran: An instruction was executed without any notable effect
+/// Printed: An instruction was executed and completed an output line
+/// Halted: The machine encountered an error and is now dead
+/// Completed: The machine has finished running the program
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepOutcome {
+    Ran,
+    Printed(String),
+    Halted(String),
+    Completed,
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub enum Flags {
     ZeroFlag = 0b00000001,
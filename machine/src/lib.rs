@@ -1,6 +1,8 @@
 use std::fmt;
 use std::fs;
 
+mod disassembler;
+mod driver;
 mod enums;
 mod errors;
 mod machine;
@@ -13,6 +15,7 @@ mod components;
 #[cfg(test)]
 mod tests;
 
+use disassembler::DebugInfo;
 use enums::{OpCodes, OperandType};
 use parser::parse;
 
@@ -51,9 +54,20 @@ impl Program {
             textual_instructions: contents,
         })
     }
+
+    /// Returns the textual form of the program's instructions. Without debug information
+    /// the result can be parsed back into the same instructions if the program came from the
+    /// parser (instructions built by hand may reference registers the parser refuses, such as
+    /// `CIP`), with debug information it is an annotated listing (labels and source lines)
+    /// meant for human review.
+    pub fn disassemble(&self, debug_info: Option<&DebugInfo>) -> String {
+        disassembler::disassemble(&self.instructions, debug_info)
+    }
 }
 
 pub mod prelude {
+    pub use super::disassembler::*;
+    pub use super::driver::*;
     pub use super::enums::*;
     pub use super::errors::*;
     pub use super::machine::*;
@@ -1,7 +1,9 @@
-use super::enums::{Flags, MachineStatus, OpCodes, OperandType, Registers};
+use super::disassembler::DebugInfo;
+use super::enums::{Flags, MachineStatus, OpCodes, OperandType, Registers, StepOutcome};
 use crate::Instruction;
 
 const REGISTER_AMOUNT: usize = 8;
+const GENERAL_REGISTER_AMOUNT: usize = 4; // GPA to GPD, saved and loaded by SAVEREGS/LOADREGS
 const STACK_SIZE: usize = 256; // 1kB of stack (each value on the stack is 4 bytes)
 const MEMORY_SIZE: usize = 65536; // 64kB of memory
 
@@ -16,6 +18,9 @@ pub struct VirtualMachine {
     status: MachineStatus,
     program: Option<Vec<Instruction>>,
     current_output: Option<String>,
+    pending_output: Vec<String>, // Values of the output line being built by PRINTC
+    call_depth: usize,           // Amount of calls that have not returned yet
+    max_call_depth: Option<usize>, // Nested calls allowed before the machine dies, unlimited if None
 }
 
 impl Default for VirtualMachine {
@@ -29,6 +34,9 @@ impl Default for VirtualMachine {
             status: MachineStatus::Empty,
             program: None,
             current_output: None,
+            pending_output: vec![],
+            call_depth: 0,
+            max_call_depth: None,
         }
     }
 }
@@ -50,6 +58,8 @@ impl VirtualMachine {
         self.flags = 0;
         self.next_flags = 0;
 
+        self.pending_output.clear();
+        self.call_depth = 0;
         self.memory = [0; MEMORY_SIZE];
         self.status = if self.program.is_some() {
             MachineStatus::Ready
@@ -64,11 +74,37 @@ impl VirtualMachine {
         self
     }
 
+    /// Limits the amount of nested calls, the machine dies when a call goes deeper
+    pub fn with_max_call_depth(mut self, max_call_depth: usize) -> VirtualMachine {
+        self.max_call_depth = Some(max_call_depth);
+        self
+    }
+
+    pub fn set_max_call_depth(&mut self, max_call_depth: Option<usize>) {
+        self.max_call_depth = max_call_depth;
+    }
+
+    pub fn get_call_depth(&self) -> usize {
+        self.call_depth
+    }
+
     pub fn load_program(&mut self, program: Vec<Instruction>) {
         self.program = Some(program);
         self.status = MachineStatus::Ready;
     }
 
+    /// Loads the program after checking it against its debug information.
+    /// Literal `call` targets must be function entries, the program is not loaded otherwise.
+    pub fn load_program_with_debug_info(
+        &mut self,
+        program: Vec<Instruction>,
+        debug_info: &DebugInfo,
+    ) -> Result<(), String> {
+        debug_info.check_call_targets(&program)?;
+        self.load_program(program);
+        Ok(())
+    }
+
     pub fn get_status(&self) -> String {
         format!("{}", self.status)
     }
@@ -110,51 +146,46 @@ impl VirtualMachine {
             .collect()
     }
 
-    // Update memory mapped properties to reflect the bot's sensors & react to the program's instructions
-    #[cfg(feature = "bevy")]
-    pub fn update_mmp(
-        &mut self,
-        transform: &mut bevy::prelude::Transform,
-        vel: &mut bevy_rapier2d::prelude::Velocity,
-    ) {
+    /// Writes the bot's position and rotation (in radians) to the memory mapped properties and
+    /// returns the linear velocity (in world coordinates) and the angular velocity (in radians
+    /// per second) requested by the program
+    pub fn sync_mmp(&mut self, position: (f32, f32), rotation: f32) -> ((f32, f32), f32) {
         use super::enums::MemoryMappedProperties;
         use std::f32::consts::PI;
 
-        use bevy::prelude::*;
-
-        let mut rotation_angle =
-            transform.rotation.to_axis_angle().0.z * transform.rotation.to_axis_angle().1;
-
         // Keep angles between 0 and 2PI
-        if rotation_angle < 0.0 {
-            rotation_angle += 2.0 * PI;
-        }
+        let rotation_angle = rotation.rem_euclid(2.0 * PI);
 
         // Write read-only to memory, read writeable from memory
-        self.memory[MemoryMappedProperties::Position as usize] = transform.translation.x as i32;
-        self.memory[MemoryMappedProperties::Position as usize + 1] = transform.translation.y as i32;
+        self.memory[MemoryMappedProperties::Position as usize] = position.0 as i32;
+        self.memory[MemoryMappedProperties::Position as usize + 1] = position.1 as i32;
         self.memory[MemoryMappedProperties::Rotation as usize] =
             (rotation_angle * (180.0 / PI)) as i32;
 
-        let velocity: Vec2 = Vec2::new(
+        // The requested velocity is relative to the bot's rotation
+        let velocity = (
             self.memory[MemoryMappedProperties::Velocity as usize] as f32,
             self.memory[MemoryMappedProperties::Velocity as usize + 1] as f32,
         );
+        let (sin, cos) = rotation_angle.sin_cos();
+        let linvel = (
+            velocity.0 * cos - velocity.1 * sin,
+            velocity.0 * sin + velocity.1 * cos,
+        );
 
-        vel.linvel = Vec2::from_angle(rotation_angle).rotate(velocity);
+        let angvel = self.memory[MemoryMappedProperties::Moment as usize] as f32 * (PI / 180.0);
 
-        vel.angvel = self.memory[MemoryMappedProperties::Moment as usize] as f32 * (PI / 180.0);
+        (linvel, angvel)
     }
 
-    /// Updates the rays values in memory
-    #[cfg(feature = "bevy")]
-    pub fn update_rays(&mut self, rays: Vec<Option<(bevy::prelude::Entity, f32)>>) {
+    /// Writes the distance of the obstacle hit by each ray to memory, None if the ray hit nothing
+    pub fn sync_rays(&mut self, rays: &[Option<f32>]) {
         use super::enums::MemoryMappedProperties;
 
         for (index, ray_data) in rays.iter().enumerate() {
-            if let Some((_ent, dist)) = ray_data {
+            if let Some(dist) = ray_data {
                 self.memory[MemoryMappedProperties::RayDist as usize + index] = *dist as i32;
-                self.memory[MemoryMappedProperties::RayType as usize + index as usize] = 1;
+                self.memory[MemoryMappedProperties::RayType as usize + index] = 1;
             } else {
                 self.memory[MemoryMappedProperties::RayDist as usize + index] = 0;
                 self.memory[MemoryMappedProperties::RayType as usize + index] = 0;
@@ -162,6 +193,61 @@ impl VirtualMachine {
         }
     }
 
+    // Update memory mapped properties to reflect the bot's sensors & react to the program's instructions
+    #[cfg(feature = "bevy")]
+    pub fn update_mmp(
+        &mut self,
+        transform: &mut bevy::prelude::Transform,
+        vel: &mut bevy_rapier2d::prelude::Velocity,
+    ) {
+        use bevy::prelude::*;
+
+        let rotation_angle =
+            transform.rotation.to_axis_angle().0.z * transform.rotation.to_axis_angle().1;
+
+        let (linvel, angvel) = self.sync_mmp(
+            (transform.translation.x, transform.translation.y),
+            rotation_angle,
+        );
+
+        vel.linvel = Vec2::new(linvel.0, linvel.1);
+        vel.angvel = angvel;
+    }
+
+    /// Updates the rays values in memory
+    #[cfg(feature = "bevy")]
+    pub fn update_rays(&mut self, rays: Vec<Option<(bevy::prelude::Entity, f32)>>) {
+        self.sync_rays(
+            &rays
+                .iter()
+                .map(|ray_data| ray_data.map(|(_ent, dist)| dist))
+                .collect::<Vec<Option<f32>>>(),
+        );
+    }
+
+    /// Returns the address of the memory region holding the general purpose registers for
+    /// `saveregs`/`loadregs`, checking that the whole region fits in memory
+    fn general_registers_address(
+        &mut self,
+        operand: &OperandType,
+        instruction: &str,
+    ) -> Result<usize, String> {
+        let address = match self.get_immediate_operand_value(operand)? {
+            Some(v) => v,
+            None => self
+                .invalid_instruction(format!("Missing operand for {} instruction", instruction))?,
+        };
+
+        if address < 0 || address as usize + GENERAL_REGISTER_AMOUNT > MEMORY_SIZE {
+            return self.invalid_instruction(format!(
+                "{} at address {} is out of memory bounds",
+                instruction, address
+            ));
+        }
+
+        Ok(address as usize)
+    }
+
     fn invalid_instruction<S: AsRef<str>, R>(&mut self, msg: S) -> Result<R, String> {
         self.status = MachineStatus::Dead;
         Err(format!(
@@ -400,18 +486,49 @@ impl VirtualMachine {
         }
     }
 
+    /// Executes the next instruction and returns an error if the machine is dead, has completed
+    /// or faulted during this tick. See `step` to know what happened during the tick.
     pub fn tick(&mut self) -> Result<(), String> {
+        if matches!(self.status, MachineStatus::Dead | MachineStatus::Complete) {
+            return Err("Machine is dead".to_string());
+        }
+
+        match self.step() {
+            StepOutcome::Halted(reason) => Err(reason),
+            _ => Ok(()),
+        }
+    }
+
+    /// Executes the next instruction and describes what happened.
+    /// A step on a completed machine keeps returning `Completed`, a step on a dead machine keeps
+    /// returning `Halted`. When the last instruction prints, `Printed` is returned and the next
+    /// step returns `Completed`.
+    pub fn step(&mut self) -> StepOutcome {
         match self.status {
-            MachineStatus::Dead | MachineStatus::Complete => {
-                return Err("Machine is dead".to_string());
-            }
-            MachineStatus::Ready => {
-                self.registers[Registers::CIP as usize] = 0i32;
-                self.status = MachineStatus::Running;
-            }
+            MachineStatus::Dead => return StepOutcome::Halted("Machine is dead".to_string()),
+            MachineStatus::Complete => return StepOutcome::Completed,
             _ => {}
         }
 
+        if let Err(reason) = self.execute() {
+            self.status = MachineStatus::Dead;
+            return StepOutcome::Halted(reason);
+        }
+
+        match &self.current_output {
+            Some(output) => StepOutcome::Printed(output.clone()),
+            None if self.has_completed() => StepOutcome::Completed,
+            None => StepOutcome::Ran,
+        }
+    }
+
+    /// Executes the instruction pointed at by the instruction pointer
+    fn execute(&mut self) -> Result<(), String> {
+        if matches!(self.status, MachineStatus::Ready) {
+            self.registers[Registers::CIP as usize] = 0i32;
+            self.status = MachineStatus::Running;
+        }
+
         let instruction: Instruction = if let Some(instruction) = self.get_current_instruction() {
             Ok(instruction)
         } else {
@@ -707,9 +824,19 @@ impl VirtualMachine {
                         self.invalid_instruction("Missing first operand for store instruction")?
                     }
                 };
+                if let Some(max_call_depth) = self.max_call_depth {
+                    if self.call_depth >= max_call_depth {
+                        self.invalid_instruction(format!(
+                            "max recursion depth exceeded ({} nested calls)",
+                            max_call_depth
+                        ))?;
+                    }
+                }
                 self.push_stack(self.registers[Registers::CIP as usize] + 1)?;
+                self.call_depth += 1;
             }
             OpCodes::RET => {
+                self.call_depth = self.call_depth.saturating_sub(1);
                 let rp = self.pop_stack()?;
                 next_jump = rp - self.registers[Registers::CIP as usize];
             }
@@ -737,7 +864,27 @@ impl VirtualMachine {
                     Some(v) => v,
                     None => self.invalid_instruction("Missing operand for print instruction")?,
                 };
-                self.current_output = Some(format!("{}", output));
+                self.pending_output.push(format!("{}", output));
+                self.current_output = Some(std::mem::take(&mut self.pending_output).join(" "));
+            }
+            OpCodes::PRINTC => {
+                let output = match self.get_operand_value(&instruction.operand_1)? {
+                    Some(v) => v,
+                    None => self.invalid_instruction("Missing operand for printc instruction")?,
+                };
+                self.pending_output.push(format!("{}", output));
+            }
+            OpCodes::SAVEREGS => {
+                let address = self.general_registers_address(&instruction.operand_1, "saveregs")?;
+                for index in 0..GENERAL_REGISTER_AMOUNT {
+                    self.memory[address + index] = self.registers[Registers::GPA as usize + index];
+                }
+            }
+            OpCodes::LOADREGS => {
+                let address = self.general_registers_address(&instruction.operand_1, "loadregs")?;
+                for index in 0..GENERAL_REGISTER_AMOUNT {
+                    self.registers[Registers::GPA as usize + index] = self.memory[address + index];
+                }
             }
             OpCodes::HLT => self.status = MachineStatus::Complete,
         }
@@ -23,6 +23,9 @@ fn parse_instr<S: AsRef<str>>(instr: S) -> Result<OpCodes, String> {
         "pop" => Ok(OpCodes::POP),
         "push" => Ok(OpCodes::PUSH),
         "print" => Ok(OpCodes::PRINT),
+        "printc" => Ok(OpCodes::PRINTC),
+        "saveregs" => Ok(OpCodes::SAVEREGS),
+        "loadregs" => Ok(OpCodes::LOADREGS),
         "halt" => Ok(OpCodes::HLT),
         _ => Err(format!("Unknown instruction: {}", instr.as_ref())),
     }
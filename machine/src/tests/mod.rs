@@ -1 +1,4 @@
+pub mod test_disassembler;
+pub mod test_driver;
+pub mod test_machine;
 pub mod test_parser;
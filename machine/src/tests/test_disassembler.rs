@@ -0,0 +1,90 @@
+use crate::prelude::{
+    disassemble, parse, DebugInfo, Instruction, OpCodes, OperandType, Program, Registers,
+};
+
+const SOURCE: &str = "mov 'SBP 'TSP
+sub 'TSP #1
+mov ['SBP - 1] #5
+mov 'GPC $Velocity
+mov 'GPD #1
+store {'GPC + 'GPD} ['SBP - 1]
+push #-3
+call #3
+add 'TSP #1
+halt
+push 'SBP
+ret";
+
+#[test]
+fn test_disassemble_round_trip() {
+    let instructions = parse(SOURCE).expect("Unable to parse the test program");
+
+    let text = disassemble(&instructions, None);
+    let reparsed = parse(&text).expect("Unable to parse the disassembled program");
+
+    assert_eq!(instructions, reparsed);
+    assert_eq!(text.lines().count(), instructions.len());
+    assert!(text.lines().all(|line| !line.ends_with(' ')));
+}
+
+#[test]
+fn test_disassemble_program() {
+    let program = Program {
+        original_file: "test.asmfg".to_string(),
+        instructions: parse(SOURCE).unwrap(),
+        textual_instructions: SOURCE.to_string(),
+    };
+
+    assert_eq!(
+        parse(program.disassemble(None)).unwrap(),
+        program.instructions
+    );
+}
+
+#[test]
+fn test_disassemble_annotated_listing() {
+    let instructions = parse(SOURCE).unwrap();
+    let mut debug_info = DebugInfo::new();
+    debug_info.add_label(0, "function_main_label");
+    debug_info.add_label(10, "function_helper_label");
+    debug_info.add_source_line(2, 3);
+    debug_info.add_source_line(11, 7);
+
+    let listing = disassemble(&instructions, Some(&debug_info));
+    let lines = listing.lines().collect::<Vec<&str>>();
+
+    assert_eq!(lines.len(), instructions.len() + 2);
+    assert_eq!(lines[0], "function_main_label:");
+    assert!(lines[1].starts_with("0000"));
+    assert!(lines[3].starts_with("0002") && lines[3].ends_with("; line 3"));
+    assert_eq!(lines[11], "function_helper_label:");
+    assert!(lines[12].starts_with("000A") && lines[12].contains("push     'SBP"));
+    assert!(lines[13].starts_with("000B") && lines[13].ends_with("; line 7"));
+
+    // Mnemonics of different lengths still align their operands
+    let operand_column = |line: &str| line.find('\'').unwrap();
+    assert_eq!(operand_column(lines[1]), operand_column(lines[2]));
+    assert_eq!(operand_column(lines[1]), operand_column(lines[12]));
+
+    // Source line comments are aligned as well
+    assert_eq!(lines[3].find(';'), lines[13].find(';'));
+}
+
+#[test]
+fn test_disassemble_instruction_pointer_does_not_round_trip() {
+    // The instruction pointer cannot be written to directly, the parser refuses it
+    let instructions = vec![Instruction {
+        opcode: OpCodes::MOV,
+        operand_1: OperandType::Register {
+            idx: Registers::GPA as usize,
+        },
+        operand_2: OperandType::Register {
+            idx: Registers::CIP as usize,
+        },
+    }];
+
+    let text = disassemble(&instructions, None);
+
+    assert_eq!(text, "mov 'GPA 'CIP");
+    assert!(parse(&text).is_err());
+}
@@ -0,0 +1,67 @@
+use std::f32::consts::PI;
+
+use crate::prelude::{parse, HeadlessDriver, StepOutcome, VirtualMachine};
+
+fn driver(program: &str) -> HeadlessDriver {
+    HeadlessDriver::new(VirtualMachine::new().with_program(parse(program).unwrap()))
+}
+
+fn assert_close(a: (f32, f32), b: (f32, f32)) {
+    assert!(
+        (a.0 - b.0).abs() < 1e-3 && (a.1 - b.1).abs() < 1e-3,
+        "{:?} != {:?}",
+        a,
+        b
+    );
+}
+
+// Sets the lateral velocity then spins forever
+const MOVE_PROGRAM: &str = "store $Velocity #60
+jmp #0";
+
+#[test]
+fn test_velocity_moves_driver() {
+    let mut driver = driver(MOVE_PROGRAM).with_position(10.0, 20.0);
+
+    assert_eq!(driver.step(), StepOutcome::Ran);
+    assert_close(driver.velocity, (60.0, 0.0));
+    assert_close(driver.position, (11.0, 20.0));
+
+    driver.step();
+    assert_close(driver.position, (12.0, 20.0));
+}
+
+#[test]
+fn test_velocity_is_relative_to_rotation() {
+    let mut driver = driver(MOVE_PROGRAM).with_rotation(PI / 2.0);
+
+    driver.step();
+    assert_close(driver.velocity, (0.0, 60.0));
+    assert_close(driver.position, (0.0, 1.0));
+}
+
+#[test]
+fn test_rays_are_readable_by_program() {
+    let mut driver = driver(
+        "mov 'GPC $RayDist
+mov 'GPD #0
+load 'GPA {'GPC + 'GPD}
+print 'GPA
+jmp #0",
+    );
+    driver.rays = vec![Some(42.0), None];
+
+    assert_eq!(driver.step(), StepOutcome::Ran);
+    assert_eq!(driver.step(), StepOutcome::Ran);
+    assert_eq!(driver.step(), StepOutcome::Ran);
+    assert_eq!(driver.step(), StepOutcome::Printed("42".to_string()));
+}
+
+#[test]
+fn test_halted_machine_does_not_move() {
+    let mut driver = driver("pop 'GPA").with_position(5.0, 5.0);
+    driver.velocity = (100.0, 0.0);
+
+    assert!(matches!(driver.step(), StepOutcome::Halted(_)));
+    assert_close(driver.position, (5.0, 5.0));
+}
@@ -0,0 +1,237 @@
+use crate::prelude::{parse, DebugInfo, StepOutcome, VirtualMachine};
+
+/// Runs the machine until it completes, returning its outputs
+fn run(machine: &mut VirtualMachine) -> Result<Vec<String>, String> {
+    let mut outputs = vec![];
+    while !machine.has_completed() {
+        machine.tick()?;
+        if let Some(output) = machine.get_current_output(true) {
+            outputs.push(output);
+        }
+    }
+    Ok(outputs)
+}
+
+// A main function calling a function at address 5, which returns its parameter + 1
+const CALL_PROGRAM: &str = "mov 'SBP 'TSP
+push #41
+call #3
+print 'FRV
+halt
+push 'SBP
+mov 'SBP 'TSP
+mov 'FRV ['SBP + 2]
+add 'FRV #1
+mov 'TSP 'SBP
+pop 'SBP
+ret";
+
+fn call_program_debug_info() -> DebugInfo {
+    let mut debug_info = DebugInfo::new();
+    debug_info.add_label(0, "function_main_label");
+    debug_info.add_label(5, "function_increment_label");
+    debug_info
+}
+
+#[test]
+fn test_call_function_entry() {
+    let mut machine = VirtualMachine::new();
+    machine
+        .load_program_with_debug_info(parse(CALL_PROGRAM).unwrap(), &call_program_debug_info())
+        .expect("Call to a function entry should be accepted");
+
+    assert_eq!(run(&mut machine), Ok(vec!["42".to_string()]));
+}
+
+#[test]
+fn test_call_into_function_body_rejected() {
+    // Jumps over the frame setup of the function
+    let program = parse(CALL_PROGRAM.replace("call #3", "call #5")).unwrap();
+
+    let mut machine = VirtualMachine::new();
+    let result = machine.load_program_with_debug_info(program, &call_program_debug_info());
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().contains("not a function entry"));
+    assert_eq!(machine.get_status(), "Empty");
+}
+
+#[test]
+fn test_call_target_out_of_range_rejected() {
+    for target in [i32::MAX, i32::MIN] {
+        let program = parse(CALL_PROGRAM.replace("call #3", &format!("call #{}", target))).unwrap();
+
+        let mut machine = VirtualMachine::new();
+        let result = machine.load_program_with_debug_info(program, &call_program_debug_info());
+
+        assert!(result.unwrap_err().contains("not a function entry"));
+    }
+}
+
+#[test]
+fn test_call_unchecked_without_function_entries() {
+    let program = parse(CALL_PROGRAM.replace("call #3", "call #5")).unwrap();
+
+    let mut machine = VirtualMachine::new();
+    assert!(machine
+        .load_program_with_debug_info(program, &DebugInfo::new())
+        .is_ok());
+}
+
+#[test]
+fn test_printc_builds_output_line() {
+    let mut machine = VirtualMachine::new().with_program(
+        parse("mov 'GPA #1\nprintc 'GPA\nprintc #2\nprint #3\nprint #4\nhalt").unwrap(),
+    );
+
+    assert_eq!(
+        run(&mut machine),
+        Ok(vec!["1 2 3".to_string(), "4".to_string()])
+    );
+}
+
+#[test]
+fn test_step_outcomes() {
+    let mut machine =
+        VirtualMachine::new().with_program(parse("mov 'GPA #5\nprint 'GPA\nhalt").unwrap());
+
+    assert_eq!(machine.step(), StepOutcome::Ran);
+    assert_eq!(machine.step(), StepOutcome::Printed("5".to_string()));
+    assert_eq!(machine.step(), StepOutcome::Completed);
+    assert!(machine.has_completed());
+
+    // Stepping a completed machine does not fault
+    assert_eq!(machine.step(), StepOutcome::Completed);
+    assert!(machine.tick().is_err());
+}
+
+#[test]
+fn test_step_print_on_last_instruction() {
+    let mut machine = VirtualMachine::new().with_program(parse("print #1").unwrap());
+
+    assert_eq!(machine.step(), StepOutcome::Printed("1".to_string()));
+    assert_eq!(machine.step(), StepOutcome::Completed);
+}
+
+#[test]
+fn test_step_fault_halts() {
+    let mut machine = VirtualMachine::new().with_program(parse("pop 'GPA\nhalt").unwrap());
+
+    assert_eq!(
+        machine.step(),
+        StepOutcome::Halted("Stack underflow".to_string())
+    );
+    assert_eq!(machine.get_status(), "Dead");
+    assert!(matches!(machine.step(), StepOutcome::Halted(_)));
+}
+
+#[test]
+fn test_saveregs_loadregs_round_trip() {
+    let program = parse(
+        "mov 'GPA #1
+mov 'GPB #-2
+mov 'GPC #3
+mov 'GPD #4
+saveregs #1000
+mov 'GPA #0
+mov 'GPB #0
+mov 'GPC #0
+mov 'GPD #0
+loadregs #1000
+printc 'GPA
+printc 'GPB
+printc 'GPC
+print 'GPD
+mov 'GPC #1000
+mov 'GPD #1
+load 'GPA {'GPC + 'GPD}
+print 'GPA",
+    )
+    .unwrap();
+    let mut machine = VirtualMachine::new().with_program(program);
+
+    assert_eq!(
+        run(&mut machine),
+        Ok(vec!["1 -2 3 4".to_string(), "-2".to_string()])
+    );
+}
+
+#[test]
+fn test_saveregs_out_of_bounds() {
+    for program in ["saveregs #65533", "loadregs #-1"] {
+        let mut machine = VirtualMachine::new().with_program(parse(program).unwrap());
+
+        match machine.step() {
+            StepOutcome::Halted(reason) => {
+                assert!(reason.contains("out of memory bounds"), "{}", reason)
+            }
+            outcome => panic!("Expected the machine to halt, got {:?}", outcome),
+        }
+    }
+}
+
+#[test]
+fn test_saveregs_at_end_of_memory() {
+    let program =
+        parse("mov 'GPD #7\nsaveregs #65532\nmov 'GPD #0\nloadregs #65532\nprint 'GPD").unwrap();
+    let mut machine = VirtualMachine::new().with_program(program);
+
+    assert_eq!(run(&mut machine), Ok(vec!["7".to_string()]));
+}
+
+// Calls a function recursing down to 0 from the pushed value, then prints its result (0)
+fn recursive_program(depth: i32) -> String {
+    format!(
+        "push #{}
+call #3
+print 'FRV
+halt
+push 'SBP
+mov 'SBP 'TSP
+mov 'GPA ['SBP + 2]
+cmp 'GPA #0
+jz #6
+sub 'GPA #1
+push 'GPA
+call #-7
+pop 'GPA
+jmp #2
+mov 'FRV #0
+mov 'TSP 'SBP
+pop 'SBP
+ret",
+        depth
+    )
+}
+
+#[test]
+fn test_shallow_recursion_runs() {
+    let program = parse(recursive_program(5)).unwrap();
+    let mut machine = VirtualMachine::new()
+        .with_program(program)
+        .with_max_call_depth(10);
+
+    assert_eq!(run(&mut machine), Ok(vec!["0".to_string()]));
+    assert_eq!(machine.get_call_depth(), 0);
+}
+
+#[test]
+fn test_recursion_exceeding_max_call_depth() {
+    let program = parse(recursive_program(20)).unwrap();
+    let mut machine = VirtualMachine::new()
+        .with_program(program)
+        .with_max_call_depth(10);
+
+    let error = run(&mut machine).unwrap_err();
+    assert!(error.contains("max recursion depth exceeded"), "{}", error);
+    assert_eq!(machine.get_call_depth(), 10);
+    assert!(matches!(machine.step(), StepOutcome::Halted(_)));
+}
+
+#[test]
+fn test_recursion_unlimited_by_default() {
+    let program = parse(recursive_program(20)).unwrap();
+    let mut machine = VirtualMachine::new().with_program(program);
+
+    assert_eq!(run(&mut machine), Ok(vec!["0".to_string()]));
+}
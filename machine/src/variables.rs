@@ -8,3 +8,11 @@ pub fn get_special_variables() -> Vec<String> {
         "$Moment".to_string(),
     ]
 }
+
+/// The amount of rays cast by a bot, `$RayDist` and `$RayType` hold one value per ray
+pub const RAY_COUNT: i32 = 7;
+
+/// Returns the constants available in the source code, along with their value
+pub fn get_constants() -> Vec<(String, i32)> {
+    vec![("RAY_COUNT".to_string(), RAY_COUNT)]
+}
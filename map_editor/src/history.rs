@@ -0,0 +1,84 @@
+use afgcore::prelude::{Map, SpawnPoint};
+
+/// An edit of the spawn points of a map that can be applied and reverted
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpawnPointEdit {
+    Add {
+        point: SpawnPoint,
+    },
+    Remove {
+        index: usize,
+        point: SpawnPoint,
+    },
+    Update {
+        index: usize,
+        before: SpawnPoint,
+        after: SpawnPoint,
+    },
+}
+
+impl SpawnPointEdit {
+    fn apply(&self, map: &mut Map) {
+        match self {
+            SpawnPointEdit::Add { point } => map.spawn_points.push(*point),
+            SpawnPointEdit::Remove { index, .. } => {
+                map.spawn_points.remove(*index);
+            }
+            SpawnPointEdit::Update { index, after, .. } => map.spawn_points[*index] = *after,
+        }
+    }
+
+    fn revert(&self, map: &mut Map) {
+        match self {
+            SpawnPointEdit::Add { .. } => {
+                map.spawn_points.pop();
+            }
+            SpawnPointEdit::Remove { index, point } => map.spawn_points.insert(*index, *point),
+            SpawnPointEdit::Update { index, before, .. } => map.spawn_points[*index] = *before,
+        }
+    }
+}
+
+/// The edits done on a map, allowing to undo and redo them
+#[derive(Debug, Default)]
+pub struct History {
+    done: Vec<SpawnPointEdit>,
+    undone: Vec<SpawnPointEdit>,
+}
+
+impl History {
+    /// Applies the edit to the map and records it. Redoing is no longer possible afterwards.
+    pub fn apply(&mut self, edit: SpawnPointEdit, map: &mut Map) {
+        edit.apply(map);
+        self.done.push(edit);
+        self.undone.clear();
+    }
+
+    /// Reverts the last edit, returns false if there was nothing to undo
+    pub fn undo(&mut self, map: &mut Map) -> bool {
+        let Some(edit) = self.done.pop() else {
+            return false;
+        };
+        edit.revert(map);
+        self.undone.push(edit);
+        true
+    }
+
+    /// Applies the last undone edit again, returns false if there was nothing to redo
+    pub fn redo(&mut self, map: &mut Map) -> bool {
+        let Some(edit) = self.undone.pop() else {
+            return false;
+        };
+        edit.apply(map);
+        self.done.push(edit);
+        true
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.done.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.undone.is_empty()
+    }
+}
@@ -1,5 +1,21 @@
+use afgcore::prelude::{Map, SpawnPoint, TEAM_COUNT};
 use eframe::egui;
 
+mod history;
+mod placement;
+
+#[cfg(test)]
+mod tests;
+
+use history::{History, SpawnPointEdit};
+use placement::{SPAWN_POINT_RADIUS, Viewport, heading, rotation_from_drag, spawn_point_at};
+
+/// Colors of the teams, indexed by team id
+const TEAM_COLORS: [egui::Color32; TEAM_COUNT as usize] = [
+    egui::Color32::from_rgb(60, 120, 220),
+    egui::Color32::from_rgb(220, 70, 60),
+];
+
 fn main() -> Result<(), ()> {
     let native_options = eframe::NativeOptions::default();
     eframe::run_native(
@@ -10,8 +26,47 @@ fn main() -> Result<(), ()> {
     .map_err(|e| println!("Error running the project: {e}"))
 }
 
-#[derive(Default)]
-struct AFGMapEditor {}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tool {
+    Select,
+    SpawnPoint,
+}
+
+/// A drag in progress on the map, anchored at a world position
+#[derive(Debug, Clone, Copy)]
+enum Drag {
+    Place { at: (f32, f32) }, // Places a new spawn point facing the drag direction
+    Rotate { index: usize },  // Rotates an existing spawn point towards the pointer
+}
+
+struct AFGMapEditor {
+    map: Map,
+    history: History,
+    tool: Tool,
+    team: u8,
+    selected: Option<usize>,
+    drag: Option<Drag>,
+}
+
+impl Default for AFGMapEditor {
+    fn default() -> Self {
+        Self {
+            map: Map {
+                title: "Untitled".to_string(),
+                size: (100, 50),
+                tile_size: 100,
+                spawn_places: ((0, 0, 0, 0), (0, 0, 0, 0)),
+                spawn_points: vec![],
+                walls: vec![],
+            },
+            history: History::default(),
+            tool: Tool::Select,
+            team: 0,
+            selected: None,
+            drag: None,
+        }
+    }
+}
 
 impl AFGMapEditor {
     fn new(cc: &eframe::CreationContext<'_>) -> Self {
@@ -21,10 +76,233 @@ impl AFGMapEditor {
         // for e.g. egui::PaintCallback.
         Self::default()
     }
+
+    fn apply(&mut self, edit: SpawnPointEdit) {
+        self.history.apply(edit, &mut self.map);
+    }
+
+    fn undo(&mut self) {
+        if self.history.undo(&mut self.map) {
+            self.selected = None;
+            self.drag = None;
+        }
+    }
+
+    fn redo(&mut self) {
+        if self.history.redo(&mut self.map) {
+            self.selected = None;
+            self.drag = None;
+        }
+    }
+
+    /// Changes the team of the newly placed spawn points and of the selected one
+    fn set_team(&mut self, team: u8) {
+        self.team = team;
+        if let Some(index) = self.selected {
+            let before = self.map.spawn_points[index];
+            if before.team != team {
+                self.apply(SpawnPointEdit::Update {
+                    index,
+                    before,
+                    after: SpawnPoint { team, ..before },
+                });
+            }
+        }
+    }
+
+    fn tools_ui(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Tools");
+        ui.radio_value(&mut self.tool, Tool::Select, "Select");
+        ui.radio_value(&mut self.tool, Tool::SpawnPoint, "Spawn point");
+
+        ui.separator();
+        let mut team = self.team;
+        egui::ComboBox::from_label("Team")
+            .selected_text(format!("Team {}", team))
+            .show_ui(ui, |ui| {
+                for id in 0..TEAM_COUNT {
+                    ui.selectable_value(&mut team, id, format!("Team {}", id));
+                }
+            });
+        if team != self.team {
+            self.set_team(team);
+        }
+
+        if let Some(index) = self.selected {
+            let point = self.map.spawn_points[index];
+            ui.separator();
+            ui.label(format!("Spawn point {}", index));
+            ui.label(format!("Position: ({:.0}, {:.0})", point.x, point.y));
+            ui.label(format!("Rotation: {:.0}°", point.rotation.to_degrees()));
+            if ui.button("Delete").clicked() {
+                self.delete_selected();
+            }
+        }
+    }
+
+    fn delete_selected(&mut self) {
+        if let Some(index) = self.selected.take() {
+            // A drag in progress may refer to the removed point
+            self.drag = None;
+            let point = self.map.spawn_points[index];
+            self.apply(SpawnPointEdit::Remove { index, point });
+        }
+    }
+
+    fn map_ui(&mut self, ui: &mut egui::Ui) {
+        let (response, painter) =
+            ui.allocate_painter(ui.available_size(), egui::Sense::click_and_drag());
+        let rect = response.rect;
+        let viewport = Viewport::fit(
+            (rect.min.x, rect.min.y),
+            (rect.width(), rect.height()),
+            self.map.world_size(),
+        );
+        let to_screen = |world: (f32, f32)| {
+            let (x, y) = viewport.world_to_screen(world);
+            egui::pos2(x, y)
+        };
+
+        self.handle_map_input(ui, &response, &viewport);
+
+        // Map background and walls
+        painter.rect_filled(
+            egui::Rect::from_two_pos(to_screen((0.0, 0.0)), to_screen(self.map.world_size())),
+            0.0,
+            egui::Color32::from_gray(30),
+        );
+        let tile_size = self.map.tile_size as f32;
+        for wall in self.map.walls.iter() {
+            painter.rect_filled(
+                egui::Rect::from_two_pos(
+                    to_screen((wall.x as f32 * tile_size, wall.y as f32 * tile_size)),
+                    to_screen((
+                        (wall.x + wall.width) as f32 * tile_size,
+                        (wall.y + wall.height) as f32 * tile_size,
+                    )),
+                ),
+                0.0,
+                egui::Color32::from_rgb(51, 51, 77),
+            );
+        }
+
+        // Spawn points, drawn with a line showing where the bot faces
+        let radius = (SPAWN_POINT_RADIUS * viewport.scale).max(4.0);
+        for (index, point) in self.map.spawn_points.iter().enumerate() {
+            let color = TEAM_COLORS[point.team as usize % TEAM_COLORS.len()];
+            let center = to_screen((point.x, point.y));
+            let (hx, hy) = heading(point.rotation);
+            painter.circle_filled(center, radius, color);
+            painter.line_segment(
+                [center, center + egui::vec2(hx, -hy) * radius * 2.0],
+                egui::Stroke::new(2.0, color),
+            );
+            if self.selected == Some(index) {
+                painter.circle_stroke(
+                    center,
+                    radius + 2.0,
+                    egui::Stroke::new(2.0, egui::Color32::WHITE),
+                );
+            }
+        }
+
+        // Preview of the drag in progress
+        if let (Some(drag), Some(pointer)) = (self.drag, response.interact_pointer_pos()) {
+            let anchor = match drag {
+                Drag::Place { at } => at,
+                Drag::Rotate { index } => {
+                    let point = self.map.spawn_points[index];
+                    (point.x, point.y)
+                }
+            };
+            painter.line_segment(
+                [to_screen(anchor), pointer],
+                egui::Stroke::new(1.0, egui::Color32::WHITE),
+            );
+        }
+    }
+
+    fn handle_map_input(&mut self, ui: &egui::Ui, response: &egui::Response, viewport: &Viewport) {
+        let reach = SPAWN_POINT_RADIUS.max(4.0 / viewport.scale);
+        let pointer_world = |pos: egui::Pos2| viewport.screen_to_world((pos.x, pos.y));
+
+        if response.clicked()
+            && let Some(pos) = response.interact_pointer_pos()
+        {
+            let world = pointer_world(pos);
+            self.selected = spawn_point_at(&self.map.spawn_points, world, reach);
+            if self.selected.is_none() && self.tool == Tool::SpawnPoint {
+                self.place_spawn_point(world, 0.0);
+            }
+        }
+
+        if response.drag_started()
+            && let Some(pos) = ui.input(|i| i.pointer.press_origin())
+        {
+            let world = pointer_world(pos);
+            self.selected = spawn_point_at(&self.map.spawn_points, world, reach);
+            self.drag = match (self.selected, self.tool) {
+                (Some(index), _) => Some(Drag::Rotate { index }),
+                (None, Tool::SpawnPoint) => Some(Drag::Place { at: world }),
+                (None, Tool::Select) => None,
+            };
+        }
+
+        if response.drag_stopped() {
+            let end = ui.input(|i| i.pointer.latest_pos()).map(pointer_world);
+            match (self.drag.take(), end) {
+                (Some(Drag::Place { at }), Some(end)) => {
+                    self.place_spawn_point(at, rotation_from_drag(at, end).unwrap_or(0.0));
+                }
+                (Some(Drag::Rotate { index }), Some(end)) => {
+                    let before = self.map.spawn_points[index];
+                    if let Some(rotation) = rotation_from_drag((before.x, before.y), end) {
+                        self.apply(SpawnPointEdit::Update {
+                            index,
+                            before,
+                            after: SpawnPoint { rotation, ..before },
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn place_spawn_point(&mut self, at: (f32, f32), rotation: f32) {
+        self.apply(SpawnPointEdit::Add {
+            point: SpawnPoint {
+                x: at.0,
+                y: at.1,
+                rotation,
+                team: self.team,
+            },
+        });
+        self.selected = Some(self.map.spawn_points.len() - 1);
+    }
 }
 
 impl eframe::App for AFGMapEditor {
     fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        let (undo, redo, delete) = ctx.input(|i| {
+            (
+                i.modifiers.command && !i.modifiers.shift && i.key_pressed(egui::Key::Z),
+                i.modifiers.command && i.modifiers.shift && i.key_pressed(egui::Key::Z),
+                i.key_pressed(egui::Key::Delete),
+            )
+        });
+        if undo {
+            self.undo();
+        }
+        if redo {
+            self.redo();
+        }
+        if delete {
+            self.delete_selected();
+        }
+
+        egui::SidePanel::left("tools").show(ctx, |ui| self.tools_ui(ui));
+
         egui::CentralPanel::default().show(ctx, |ui| {
             egui::MenuBar::new().ui(ui, |ui| {
                 ui.menu_button("File", |ui| {
@@ -51,11 +329,23 @@ impl eframe::App for AFGMapEditor {
                 });
 
                 ui.menu_button("Edit", |ui| {
-                    ui.add_enabled(false, egui::Button::new("Soon"))
+                    if ui
+                        .add_enabled(self.history.can_undo(), egui::Button::new("Undo"))
+                        .clicked()
+                    {
+                        self.undo();
+                    }
+
+                    if ui
+                        .add_enabled(self.history.can_redo(), egui::Button::new("Redo"))
+                        .clicked()
+                    {
+                        self.redo();
+                    }
                 })
             });
 
-            ui.heading("Hello World!");
+            self.map_ui(ui);
         });
     }
 }
@@ -0,0 +1,76 @@
+use std::f32::consts::PI;
+
+use afgcore::prelude::SpawnPoint;
+
+/// Drags shorter than this (in world units) do not change the rotation of a spawn point
+pub const MIN_DRAG_DISTANCE: f32 = 1.0;
+/// Radius of a bot, used to draw and pick spawn points (in world units)
+pub const SPAWN_POINT_RADIUS: f32 = 25.0;
+
+/// Maps world coordinates (y pointing up) to screen coordinates (y pointing down)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    pub origin: (f32, f32), // Screen position of the world origin
+    pub scale: f32,         // Amount of screen pixels per world unit
+}
+
+impl Viewport {
+    /// Returns the viewport fitting a world of the given size in the screen area starting at
+    /// `area_min` (top left corner), the world origin being at the bottom left of the area
+    pub fn fit(area_min: (f32, f32), area_size: (f32, f32), world_size: (f32, f32)) -> Self {
+        let scale = (area_size.0 / world_size.0).min(area_size.1 / world_size.1);
+        Self {
+            origin: (area_min.0, area_min.1 + world_size.1 * scale),
+            scale,
+        }
+    }
+
+    pub fn screen_to_world(&self, screen: (f32, f32)) -> (f32, f32) {
+        (
+            (screen.0 - self.origin.0) / self.scale,
+            (self.origin.1 - screen.1) / self.scale,
+        )
+    }
+
+    pub fn world_to_screen(&self, world: (f32, f32)) -> (f32, f32) {
+        (
+            self.origin.0 + world.0 * self.scale,
+            self.origin.1 - world.1 * self.scale,
+        )
+    }
+}
+
+/// Returns the rotation making a bot placed at `from` face `to`, both in world coordinates.
+/// A rotation of 0 faces up, the result is in ]-PI, PI]. Returns None if the drag is too
+/// short to give a meaningful direction.
+pub fn rotation_from_drag(from: (f32, f32), to: (f32, f32)) -> Option<f32> {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    if dx.hypot(dy) < MIN_DRAG_DISTANCE {
+        return None;
+    }
+
+    let rotation = dy.atan2(dx) - PI / 2.0;
+    Some(if rotation <= -PI {
+        rotation + 2.0 * PI
+    } else {
+        rotation
+    })
+}
+
+/// Returns the direction (unit vector, in world coordinates) a bot with the given rotation faces
+pub fn heading(rotation: f32) -> (f32, f32) {
+    let angle = rotation + PI / 2.0;
+    (angle.cos(), angle.sin())
+}
+
+/// Returns the index of the spawn point closest to the position, if it is within reach of
+/// the position (in world units)
+pub fn spawn_point_at(points: &[SpawnPoint], position: (f32, f32), reach: f32) -> Option<usize> {
+    points
+        .iter()
+        .enumerate()
+        .map(|(index, point)| (index, (point.x - position.0).hypot(point.y - position.1)))
+        .filter(|(_, distance)| *distance <= reach)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, _)| index)
+}
@@ -0,0 +1,217 @@
+use std::f32::consts::PI;
+
+use afgcore::prelude::{Map, SpawnPoint};
+
+use super::history::{History, SpawnPointEdit};
+use super::{AFGMapEditor, Drag};
+use super::placement::*;
+
+fn assert_close(a: (f32, f32), b: (f32, f32)) {
+    assert!(
+        (a.0 - b.0).abs() < 1e-3 && (a.1 - b.1).abs() < 1e-3,
+        "{:?} != {:?}",
+        a,
+        b
+    );
+}
+
+fn spawn_point(x: f32, y: f32) -> SpawnPoint {
+    SpawnPoint {
+        x,
+        y,
+        rotation: 0.0,
+        team: 0,
+    }
+}
+
+fn empty_map() -> Map {
+    Map {
+        title: "test".to_string(),
+        size: (10, 5),
+        tile_size: 100,
+        spawn_places: ((0, 0, 0, 0), (0, 0, 0, 0)),
+        spawn_points: vec![],
+        walls: vec![],
+    }
+}
+
+// ========================================
+// Placement Tests
+// ========================================
+
+#[test]
+fn test_viewport_fit() {
+    // A 1000x500 world in a 400x400 area: the width limits the scale
+    let viewport = Viewport::fit((10.0, 20.0), (400.0, 400.0), (1000.0, 500.0));
+
+    assert_eq!(viewport.scale, 0.4);
+    assert_eq!(viewport.origin, (10.0, 220.0));
+}
+
+#[test]
+fn test_click_to_world() {
+    let viewport = Viewport::fit((10.0, 20.0), (400.0, 400.0), (1000.0, 500.0));
+
+    // The bottom left corner of the map is the world origin, the y axis points up
+    assert_close(viewport.screen_to_world((10.0, 220.0)), (0.0, 0.0));
+    assert_close(viewport.screen_to_world((410.0, 20.0)), (1000.0, 500.0));
+    assert_close(viewport.screen_to_world((50.0, 200.0)), (100.0, 50.0));
+}
+
+#[test]
+fn test_world_to_screen_round_trip() {
+    let viewport = Viewport {
+        origin: (12.0, 340.0),
+        scale: 0.25,
+    };
+
+    let world = (123.0, 456.0);
+    assert_close(
+        viewport.screen_to_world(viewport.world_to_screen(world)),
+        world,
+    );
+}
+
+#[test]
+fn test_rotation_from_drag() {
+    let rotation = |to| rotation_from_drag((100.0, 100.0), to).unwrap();
+
+    // A rotation of 0 faces up, rotations go counter-clockwise
+    assert!(rotation((100.0, 200.0)).abs() < 1e-6);
+    assert!((rotation((0.0, 100.0)) - PI / 2.0).abs() < 1e-6);
+    assert!((rotation((200.0, 100.0)) + PI / 2.0).abs() < 1e-6);
+    assert!((rotation((100.0, 0.0)) - PI).abs() < 1e-6);
+}
+
+#[test]
+fn test_rotation_from_short_drag() {
+    assert_eq!(rotation_from_drag((100.0, 100.0), (100.0, 100.0)), None);
+    assert_eq!(rotation_from_drag((100.0, 100.0), (100.5, 100.0)), None);
+}
+
+#[test]
+fn test_heading_matches_drag() {
+    let rotation = rotation_from_drag((0.0, 0.0), (3.0, 4.0)).unwrap();
+    assert_close(heading(rotation), (0.6, 0.8));
+}
+
+#[test]
+fn test_spawn_point_at() {
+    let points = [spawn_point(0.0, 0.0), spawn_point(40.0, 0.0)];
+
+    assert_eq!(spawn_point_at(&points, (5.0, 0.0), 25.0), Some(0));
+    assert_eq!(spawn_point_at(&points, (30.0, 0.0), 25.0), Some(1));
+    assert_eq!(spawn_point_at(&points, (0.0, 100.0), 25.0), None);
+}
+
+// ========================================
+// History Tests
+// ========================================
+
+#[test]
+fn test_undo_redo_spawn_point_edits() {
+    let mut map = empty_map();
+    let mut history = History::default();
+
+    let placed = spawn_point(100.0, 100.0);
+    let moved = SpawnPoint {
+        rotation: PI,
+        team: 1,
+        ..placed
+    };
+    history.apply(SpawnPointEdit::Add { point: placed }, &mut map);
+    history.apply(
+        SpawnPointEdit::Update {
+            index: 0,
+            before: placed,
+            after: moved,
+        },
+        &mut map,
+    );
+    assert_eq!(map.spawn_points, vec![moved]);
+
+    assert!(history.undo(&mut map));
+    assert_eq!(map.spawn_points, vec![placed]);
+    assert!(history.undo(&mut map));
+    assert!(map.spawn_points.is_empty());
+    assert!(!history.undo(&mut map));
+
+    assert!(history.redo(&mut map));
+    assert!(history.redo(&mut map));
+    assert_eq!(map.spawn_points, vec![moved]);
+    assert!(!history.redo(&mut map));
+}
+
+#[test]
+fn test_undo_remove_restores_position() {
+    let mut map = empty_map();
+    let mut history = History::default();
+
+    let points = [
+        spawn_point(0.0, 0.0),
+        spawn_point(1.0, 0.0),
+        spawn_point(2.0, 0.0),
+    ];
+    for point in points {
+        history.apply(SpawnPointEdit::Add { point }, &mut map);
+    }
+
+    history.apply(
+        SpawnPointEdit::Remove {
+            index: 1,
+            point: points[1],
+        },
+        &mut map,
+    );
+    assert_eq!(map.spawn_points, vec![points[0], points[2]]);
+
+    history.undo(&mut map);
+    assert_eq!(map.spawn_points, points.to_vec());
+}
+
+#[test]
+fn test_new_edit_clears_redo() {
+    let mut map = empty_map();
+    let mut history = History::default();
+
+    history.apply(
+        SpawnPointEdit::Add {
+            point: spawn_point(0.0, 0.0),
+        },
+        &mut map,
+    );
+    history.undo(&mut map);
+    assert!(history.can_redo());
+
+    history.apply(
+        SpawnPointEdit::Add {
+            point: spawn_point(1.0, 0.0),
+        },
+        &mut map,
+    );
+    assert!(!history.can_redo());
+    assert_eq!(map.spawn_points, vec![spawn_point(1.0, 0.0)]);
+}
+
+#[test]
+fn test_edits_cancel_drag_in_progress() {
+    let mut editor = AFGMapEditor::default();
+    editor.apply(SpawnPointEdit::Add {
+        point: spawn_point(10.0, 10.0),
+    });
+
+    editor.selected = Some(0);
+    editor.drag = Some(Drag::Rotate { index: 0 });
+    editor.delete_selected();
+    assert!(editor.drag.is_none());
+    assert!(editor.map.spawn_points.is_empty());
+
+    editor.drag = Some(Drag::Place { at: (0.0, 0.0) });
+    editor.undo();
+    assert!(editor.drag.is_none());
+
+    editor.drag = Some(Drag::Rotate { index: 0 });
+    editor.redo();
+    assert!(editor.drag.is_none());
+    assert!(editor.map.spawn_points.is_empty());
+}
@@ -1,25 +1,9 @@
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
-use serde::Deserialize;
 
 use super::state::AppState;
 
-#[derive(Debug, Deserialize)]
-pub struct Wall {
-    pub x: i32,
-    pub y: i32,
-    pub width: i32,
-    pub height: i32,
-}
-
-#[derive(serde::Deserialize, bevy::asset::Asset, bevy::reflect::TypePath)]
-pub struct Map {
-    pub title: String,
-    pub size: (i32, i32),
-    pub tile_size: i32,
-    pub spawn_places: ((i32, i32, i32, i32), (i32, i32, i32, i32)),
-    pub walls: Vec<Wall>,
-}
+pub use afgcore::prelude::{Map, Wall};
 
 #[derive(Resource)]
 pub struct MapHandle(pub Handle<Map>);
@@ -1,6 +1,7 @@
 use std::f32::consts::PI;
 
 use bevy::prelude::*;
+use machine::prelude::RAY_COUNT;
 
 #[derive(Component)]
 pub struct IsSelected;
@@ -55,7 +56,7 @@ impl BotClass {
             health: Health::new(100.0),
             gun: Gun::new(GunType::Rifle),
             view_angle: 120.0 * PI / 180.0,
-            resolution: 7,
+            resolution: RAY_COUNT as u8,
             view_distance: 2000.0,
         }
     }
@@ -69,7 +70,7 @@ impl BotClass {
             health: Health::new(75.0),
             gun: Gun::new(GunType::Sniper),
             view_angle: 60.0 * PI / 180.0,
-            resolution: 7,
+            resolution: RAY_COUNT as u8,
             view_distance: 5000.0,
         }
     }